@@ -0,0 +1,51 @@
+use std::fmt::Write;
+use std::path::Path;
+
+#[path = "build/numbered.rs"]
+mod numbered;
+use numbered::numbered_entries;
+
+// Every `src/solutions/yYYYY/dayNN.rs` registers itself just by existing: this
+// declares a module for each year and day and lists them in `DAYS`, so adding
+// a day never means editing `solutions/mod.rs`.
+fn main() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let solutions = Path::new(&manifest_dir).join("src/solutions");
+    println!("cargo:rerun-if-changed={}", solutions.display());
+
+    let mut years = numbered_entries(&solutions, "y", "", 4);
+    years.sort();
+
+    let mut code = String::new();
+    let mut days = Vec::new();
+    for (year, name) in &years {
+        let dir = solutions.join(name);
+        println!("cargo:rerun-if-changed={}", dir.display());
+
+        let mut year_days = numbered_entries(&dir, "day", ".rs", 2);
+        year_days.sort();
+
+        writeln!(code, "pub mod y{} {{", year).unwrap();
+        for (day, name) in &year_days {
+            let path = dir.join(name);
+            writeln!(code, "    #[path = {:?}]", path.display().to_string()).unwrap();
+            writeln!(code, "    pub mod day{:02};", day).unwrap();
+            days.push((*year, *day));
+        }
+        writeln!(code, "}}").unwrap();
+    }
+
+    writeln!(code, "\nstatic DAYS: &[Day] = &[").unwrap();
+    for (year, day) in &days {
+        writeln!(
+            code,
+            "    Day::new({0}, {1}, y{0}::day{1:02}::TITLE, &y{0}::day{1:02}::Problem),",
+            year, day
+        )
+        .unwrap();
+    }
+    writeln!(code, "];").unwrap();
+
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("days.rs");
+    std::fs::write(out, code).expect("OUT_DIR should be writable");
+}
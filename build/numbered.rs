@@ -0,0 +1,24 @@
+//! File name matching for `build.rs`, kept apart so tests can include it.
+
+use std::path::Path;
+
+/// Entries of `dir` named `{prefix}{number}{suffix}` where the number has
+/// exactly `digits` digits, such as `y2021` or `day07.rs`.
+pub fn numbered_entries(
+    dir: &Path,
+    prefix: &str,
+    suffix: &str,
+    digits: usize,
+) -> Vec<(u32, String)> {
+    std::fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("{} should be readable: {}", dir.display(), err))
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            if number.len() != digits || !number.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            Some((number.parse().ok()?, name))
+        })
+        .collect()
+}
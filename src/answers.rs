@@ -0,0 +1,142 @@
+use crate::config;
+use crate::runner::{self, PartOutcome};
+use crate::solutions::Day;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// Known answers for a day live in `answers/YYYY/NN`, or under
+/// `answers/<profile>` for a profile, one line per part. An empty or missing
+/// line means that part has no recorded answer yet.
+pub fn answers_file(day: &Day) -> String {
+    let dir = config::account_dir("answers");
+    format!("{}/{}/{:02}", dir.display(), day.year, day.number)
+}
+
+pub fn load(day: &Day) -> io::Result<[Option<String>; 2]> {
+    read(answers_file(day))
+}
+
+pub fn save(day: &Day, answers: &[Option<String>; 2]) -> io::Result<()> {
+    write(answers_file(day), answers)
+}
+
+/// Reads an answers file, which needn't exist yet.
+pub fn read(path: impl AsRef<Path>) -> io::Result<[Option<String>; 2]> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+
+    let mut lines = contents.lines().map(|line| match line.trim() {
+        "" => None,
+        answer => Some(answer.to_string()),
+    });
+    Ok([lines.next().flatten(), lines.next().flatten()])
+}
+
+pub fn write(path: impl AsRef<Path>, answers: &[Option<String>; 2]) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let [first, second] = answers;
+    std::fs::write(
+        path,
+        format!(
+            "{}\n{}\n",
+            first.as_deref().unwrap_or_default(),
+            second.as_deref().unwrap_or_default()
+        ),
+    )
+}
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+    skipped: usize,
+    recorded: usize,
+}
+
+/// Runs `days` and compares every part against the recorded answers, printing a
+/// line per part. With `record`, answers that were computed successfully get
+/// written to the store instead of being checked. Each part gets at most
+/// `timeout`, if given.
+///
+/// Returns whether every part matched its recorded answer.
+pub fn verify(days: &[&Day], record: bool, timeout: Option<Duration>) -> io::Result<bool> {
+    let mut tally = Tally::default();
+
+    for day in days {
+        let mut answers = load(day)?;
+        let mut changed = false;
+
+        for (i, outcome) in runner::run_parts(day, timeout).into_iter().enumerate() {
+            let outcome = match outcome {
+                Some(outcome) => outcome,
+                None => continue,
+            };
+            let label = format!("Day {:02} part {}", day.number, i + 1);
+
+            match (outcome, &answers[i]) {
+                (PartOutcome::Solved(answer, ..), _) if record => {
+                    if answers[i].as_ref() != Some(&answer) {
+                        println!("{}: recorded {}", label, answer);
+                        answers[i] = Some(answer);
+                        changed = true;
+                        tally.recorded += 1;
+                    } else {
+                        println!("{}: pass", label);
+                        tally.passed += 1;
+                    }
+                }
+                (PartOutcome::Solved(answer, ..), Some(expected)) if answer == *expected => {
+                    println!("{}: pass", label);
+                    tally.passed += 1;
+                }
+                (PartOutcome::Solved(answer, ..), Some(expected)) => {
+                    println!("{}: FAIL (expected {}, got {})", label, expected, answer);
+                    tally.failed += 1;
+                }
+                (PartOutcome::Solved(answer, ..), None) => {
+                    println!("{}: missing (got {})", label, answer);
+                    tally.missing += 1;
+                }
+                // Parts with nothing to solve, like day 25's second, have no
+                // answer to check.
+                (PartOutcome::Failed(failure, _), None) if failure.is_not_implemented() => {
+                    println!("{}: skipped (not implemented)", label);
+                    tally.skipped += 1;
+                }
+                (PartOutcome::Failed(failure, _), _) => {
+                    println!("{}: FAIL ({})", label, first_line(&failure.to_string()));
+                    tally.failed += 1;
+                }
+            }
+        }
+
+        if changed {
+            save(day, &answers)?;
+        }
+    }
+
+    if record {
+        println!(
+            "{} recorded, {} unchanged, {} failed",
+            tally.recorded, tally.passed, tally.failed
+        );
+    } else {
+        println!(
+            "{} passed, {} failed, {} missing, {} skipped",
+            tally.passed, tally.failed, tally.missing, tally.skipped
+        );
+    }
+    Ok(tally.failed == 0)
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or_default()
+}
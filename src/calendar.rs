@@ -0,0 +1,126 @@
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
+use std::error::Error;
+use std::fmt::Display;
+use std::time::Duration;
+
+/// The last day of an event.
+pub const LAST_DAY: isize = 25;
+
+/// Where the current time comes from, so date logic can be tested with a
+/// fixed one.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Puzzles unlock at midnight US Eastern time. December is always standard
+/// time there, so a fixed UTC-5 offset is exact for the whole event.
+fn eastern() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CalendarError {
+    /// There's no puzzle for the given Eastern date.
+    OutsideEvent(NaiveDate),
+    /// The event has no such day.
+    NoSuchDay(isize),
+    /// Every puzzle of the year's event has already unlocked.
+    EventOver(isize),
+}
+
+impl Display for CalendarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalendarError::OutsideEvent(date) => write!(
+                f,
+                "It's {} in US Eastern time, which has no puzzle. Pick one with --day",
+                date
+            ),
+            CalendarError::NoSuchDay(day) => write!(
+                f,
+                "There's no day {}, puzzles go from day 1 to {}",
+                day, LAST_DAY
+            ),
+            CalendarError::EventOver(year) => write!(
+                f,
+                "Every puzzle of {} has unlocked, there's nothing to wait for",
+                year
+            ),
+        }
+    }
+}
+
+impl Error for CalendarError {}
+
+/// Today's puzzle: the day of the month in US Eastern time, as long as that's
+/// between December 1st and 25th.
+pub fn current_day(clock: &impl Clock) -> Result<isize, CalendarError> {
+    let date = clock.now().with_timezone(&eastern()).naive_local().date();
+    let day = date.day() as isize;
+    if date.month() == 12 && day <= LAST_DAY {
+        Ok(day)
+    } else {
+        Err(CalendarError::OutsideEvent(date))
+    }
+}
+
+/// The next puzzle to unlock, as a year and day: tomorrow's, in US Eastern
+/// time. On the event's last day, there's none left.
+pub fn next_day(clock: &impl Clock) -> Result<(isize, isize), CalendarError> {
+    let date = clock.now().with_timezone(&eastern()).naive_local().date();
+    if date.month() == 12 && date.day() as isize == LAST_DAY {
+        return Err(CalendarError::EventOver(date.year() as isize));
+    }
+    let tomorrow = date.succ_opt().unwrap();
+    let day = tomorrow.day() as isize;
+    if tomorrow.month() == 12 && day <= LAST_DAY {
+        Ok((tomorrow.year() as isize, day))
+    } else {
+        Err(CalendarError::OutsideEvent(date))
+    }
+}
+
+/// When `day` of `year` unlocks.
+pub fn unlock_time(year: isize, day: isize) -> Result<DateTime<Utc>, CalendarError> {
+    if !(1..=LAST_DAY).contains(&day) {
+        return Err(CalendarError::NoSuchDay(day));
+    }
+    let midnight = NaiveDate::from_ymd_opt(year as i32, 12, day as u32)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .ok_or(CalendarError::NoSuchDay(day))?;
+    let unlock = eastern()
+        .from_local_datetime(&midnight)
+        .single()
+        .ok_or(CalendarError::NoSuchDay(day))?;
+    Ok(unlock.with_timezone(&Utc))
+}
+
+/// How long until `day` of `year` unlocks, or `None` if it already has.
+pub fn until_unlock(
+    clock: &impl Clock,
+    year: isize,
+    day: isize,
+) -> Result<Option<Duration>, CalendarError> {
+    Ok((unlock_time(year, day)? - clock.now()).to_std().ok())
+}
+
+/// How long to wait for `day` of `year`, like `until_unlock`, but refusing to
+/// wait on an event whose puzzles have all unlocked.
+pub fn wait_for(
+    clock: &impl Clock,
+    year: isize,
+    day: isize,
+) -> Result<Option<Duration>, CalendarError> {
+    if unlock_time(year, LAST_DAY)? < clock.now() {
+        return Err(CalendarError::EventOver(year));
+    }
+    until_unlock(clock, year, day)
+}
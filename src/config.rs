@@ -0,0 +1,423 @@
+//! Settings from `aoc.toml`, overridden by the environment and then by
+//! command-line flags.
+//!
+//! ```toml
+//! token = "53616c746564..."
+//! year = 2021
+//! format = "text"
+//! color = "auto"
+//! base_url = "https://adventofcode.com"
+//!
+//! [profiles.alice]
+//! token = "..."
+//! ```
+//!
+//! A profile's settings override the top-level ones, except for the token:
+//! that's the account, so a profile never borrows another's. Each profile
+//! keeps its own inputs, answers, guesses and puzzle pages too, under
+//! `inputs/<profile>` and so on, since every account gets different inputs. A
+//! top-level `inputs` directory, `AOC_INPUTS` or `--inputs` gets a `<profile>`
+//! subdirectory the same way.
+
+use crate::download::BASE_URL;
+use crate::output::Format;
+use crate::solutions::DEFAULT_YEAR;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// The config file in use: `aoc.toml` in the current directory, or else in
+/// `$XDG_CONFIG_HOME` (`~/.config` by default).
+pub fn locate() -> Option<PathBuf> {
+    let local = PathBuf::from(CONFIG_FILE);
+    if local.exists() {
+        return Some(local);
+    }
+    let config_home = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(config_home.join(CONFIG_FILE)).filter(|path| path.exists())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Colour when writing to a terminal.
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Color::Auto),
+            "always" => Ok(Color::Always),
+            "never" => Ok(Color::Never),
+            _ => Err(format!(
+                "unknown colour setting '{}', expected auto, always or never",
+                s
+            )),
+        }
+    }
+}
+
+/// Settings from one source. Unset ones fall through to the next source.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Section {
+    pub token: Option<String>,
+    pub year: Option<isize>,
+    pub inputs: Option<PathBuf>,
+    pub format: Option<Format>,
+    pub color: Option<Color>,
+    pub base_url: Option<String>,
+}
+
+impl Section {
+    /// These settings, with the unset ones taken from `fallback`.
+    pub fn or(self, fallback: Section) -> Section {
+        Section {
+            token: self.token.or(fallback.token),
+            year: self.year.or(fallback.year),
+            inputs: self.inputs.or(fallback.inputs),
+            format: self.format.or(fallback.format),
+            color: self.color.or(fallback.color),
+            base_url: self.base_url.or(fallback.base_url),
+        }
+    }
+
+    /// Reads the overrides from the environment: `AOC_YEAR`, `AOC_INPUTS`,
+    /// `AOC_FORMAT`, `AOC_COLOR`, `AOC_BASE_URL`, and the session token from
+    /// `TOKEN`, or `TOKEN_ALICE` for profile `alice`, so one account's token
+    /// never gets used for another's profile. For the same reason profile `alice` reads
+    /// its inputs from `$AOC_INPUTS/alice`.
+    pub fn from_env(profile: Option<&str>) -> Result<Section, ConfigError> {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        let token_var = match profile {
+            Some(profile) => format!("TOKEN_{}", env_suffix(profile)),
+            None => "TOKEN".to_string(),
+        };
+        let origin = "the environment";
+
+        Ok(Section {
+            token: var(&token_var),
+            year: var("AOC_YEAR")
+                .map(|year| {
+                    year.parse().map_err(|_| ConfigError::Invalid {
+                        origin: origin.to_string(),
+                        key: "AOC_YEAR".to_string(),
+                        message: format!("'{}' is not a year", year),
+                    })
+                })
+                .transpose()?,
+            inputs: var("AOC_INPUTS").map(|dir| account_path(dir, profile)),
+            format: parse_setting(origin, "AOC_FORMAT", var("AOC_FORMAT").as_deref())?,
+            color: parse_setting(origin, "AOC_COLOR", var("AOC_COLOR").as_deref())?,
+            base_url: var("AOC_BASE_URL"),
+        })
+    }
+}
+
+fn env_suffix(profile: &str) -> String {
+    profile
+        .chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            c @ ('A'..='Z' | '0'..='9') => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    /// The profile to use when none is picked with `--profile`.
+    pub profile: Option<String>,
+    pub defaults: Section,
+    pub profiles: BTreeMap<String, Section>,
+}
+
+impl Config {
+    /// Reads the config file at `path`, or the default config if there's none.
+    pub fn load(path: Option<&Path>) -> Result<Config, ConfigError> {
+        let path = match path {
+            Some(path) => path,
+            None => return Ok(Config::default()),
+        };
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| ConfigError::Io(path.to_path_buf(), err))?;
+        Config::parse(&text, &path.display().to_string())
+    }
+
+    /// Parses a config file's contents, naming it `source` in errors.
+    pub fn parse(text: &str, source: &str) -> Result<Config, ConfigError> {
+        let table: toml::value::Table =
+            toml::from_str(text).map_err(|err| ConfigError::Invalid {
+                origin: source.to_string(),
+                key: String::new(),
+                message: err.to_string(),
+            })?;
+
+        let mut config = Config {
+            profile: match table.get("profile") {
+                Some(value) => Some(string(source, "profile", value)?.to_string()),
+                None => None,
+            },
+            defaults: section(source, "", &table)?,
+            profiles: BTreeMap::new(),
+        };
+        if let Some(profiles) = table.get("profiles") {
+            let profiles = profiles.as_table().ok_or_else(|| ConfigError::Invalid {
+                origin: source.to_string(),
+                key: "profiles".to_string(),
+                message: "expected a table of profiles".to_string(),
+            })?;
+            for (name, profile) in profiles {
+                let key = format!("profiles.{}.", name);
+                let profile = profile.as_table().ok_or_else(|| ConfigError::Invalid {
+                    origin: source.to_string(),
+                    key: format!("profiles.{}", name),
+                    message: "expected a table of settings".to_string(),
+                })?;
+                config
+                    .profiles
+                    .insert(name.clone(), section(source, &key, profile)?);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Settings for `profile`, or the config's own default profile: `overrides`
+    /// first, then the profile's settings, the top-level ones and the built-in
+    /// defaults.
+    pub fn resolve(
+        &self,
+        profile: Option<&str>,
+        overrides: Section,
+    ) -> Result<Settings, ConfigError> {
+        let profile = profile.or(self.profile.as_deref());
+        let from_profile = match profile {
+            Some(name) => match self.profiles.get(name) {
+                Some(section) => section.clone(),
+                None => {
+                    return Err(ConfigError::UnknownProfile {
+                        name: name.to_string(),
+                        available: self.profiles.keys().cloned().collect(),
+                    })
+                }
+            },
+            None => Section::default(),
+        };
+        let defaults = Section {
+            token: self.defaults.token.clone().filter(|_| profile.is_none()),
+            inputs: self
+                .defaults
+                .inputs
+                .as_ref()
+                .map(|dir| account_path(dir, profile)),
+            ..self.defaults.clone()
+        };
+        let section = overrides.or(from_profile).or(defaults);
+
+        Ok(Settings {
+            profile: profile.map(str::to_string),
+            token: section.token,
+            year: section.year.unwrap_or(DEFAULT_YEAR),
+            inputs: section
+                .inputs
+                .unwrap_or_else(|| account_path("inputs", profile)),
+            format: section.format.unwrap_or(Format::Text),
+            color: section.color.unwrap_or(Color::Auto),
+            base_url: section.base_url.unwrap_or_else(|| BASE_URL.to_string()),
+        })
+    }
+}
+
+const KEYS: [&str; 6] = ["token", "year", "inputs", "format", "color", "base_url"];
+
+fn section(source: &str, prefix: &str, table: &toml::value::Table) -> Result<Section, ConfigError> {
+    let key = |key: &str| format!("{}{}", prefix, key);
+    let invalid = |name: &str, message: &str| ConfigError::Invalid {
+        origin: source.to_string(),
+        key: key(name),
+        message: message.to_string(),
+    };
+    for name in table.keys() {
+        let top_level = prefix.is_empty() && (name == "profile" || name == "profiles");
+        if !top_level && !KEYS.contains(&name.as_str()) {
+            return Err(invalid(name, "unknown setting"));
+        }
+    }
+
+    let get = |name: &str| -> Result<Option<&str>, ConfigError> {
+        table
+            .get(name)
+            .map(|value| string(source, &key(name), value))
+            .transpose()
+    };
+
+    Ok(Section {
+        token: get("token")?.map(str::to_string),
+        year: match table.get("year") {
+            Some(year) => Some(
+                year.as_integer()
+                    .ok_or_else(|| invalid("year", "expected a number"))? as isize,
+            ),
+            None => None,
+        },
+        inputs: get("inputs")?.map(PathBuf::from),
+        format: parse_setting(source, &key("format"), get("format")?)?,
+        color: parse_setting(source, &key("color"), get("color")?)?,
+        base_url: get("base_url")?.map(str::to_string),
+    })
+}
+
+fn parse_setting<T: FromStr<Err = String>>(
+    origin: &str,
+    key: &str,
+    value: Option<&str>,
+) -> Result<Option<T>, ConfigError> {
+    value
+        .map(|value| {
+            value.parse().map_err(|message| ConfigError::Invalid {
+                origin: origin.to_string(),
+                key: key.to_string(),
+                message,
+            })
+        })
+        .transpose()
+}
+
+fn string<'a>(source: &str, key: &str, value: &'a toml::Value) -> Result<&'a str, ConfigError> {
+    value.as_str().ok_or_else(|| ConfigError::Invalid {
+        origin: source.to_string(),
+        key: key.to_string(),
+        message: "expected a string".to_string(),
+    })
+}
+
+/// The site to download from and submit to.
+pub fn base_url() -> String {
+    match LAYOUT.get() {
+        Some(layout) => layout.base_url.clone(),
+        None => BASE_URL.to_string(),
+    }
+}
+
+/// Where `kind` of per-account data lives: `kind` itself, or `kind/<profile>`
+/// for a profile.
+pub fn account_path(kind: impl AsRef<Path>, profile: Option<&str>) -> PathBuf {
+    let kind = kind.as_ref();
+    match profile {
+        Some(profile) => kind.join(profile),
+        None => kind.to_path_buf(),
+    }
+}
+
+/// Everything resolved, with a value for each setting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub profile: Option<String>,
+    pub token: Option<String>,
+    pub year: isize,
+    pub inputs: PathBuf,
+    pub format: Format,
+    pub color: Color,
+    /// Where puzzles, inputs and answers are fetched from and submitted to.
+    pub base_url: String,
+}
+
+struct Layout {
+    profile: Option<String>,
+    inputs: PathBuf,
+    base_url: String,
+}
+
+static LAYOUT: OnceLock<Layout> = OnceLock::new();
+
+/// Makes the file helpers like `input_file` use `settings`' profile and input
+/// directory for the rest of the process. Only the first call has an effect.
+pub fn activate(settings: &Settings) {
+    let _ = LAYOUT.set(Layout {
+        profile: settings.profile.clone(),
+        inputs: settings.inputs.clone(),
+        base_url: settings.base_url.clone(),
+    });
+}
+
+/// Where inputs are read from and downloaded to.
+pub fn inputs_dir() -> PathBuf {
+    match LAYOUT.get() {
+        Some(layout) => layout.inputs.clone(),
+        None => PathBuf::from("inputs"),
+    }
+}
+
+/// Where `kind` of per-account data, like `answers` or `guesses`, lives for
+/// the active profile.
+pub fn account_dir(kind: &str) -> PathBuf {
+    let profile = LAYOUT.get().and_then(|layout| layout.profile.as_deref());
+    account_path(kind, profile)
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Invalid {
+        /// The file or "the environment".
+        origin: String,
+        key: String,
+        message: String,
+    },
+    UnknownProfile {
+        name: String,
+        available: Vec<String>,
+    },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(path, err) => write!(f, "Could not read {}: {}", path.display(), err),
+            ConfigError::Invalid {
+                origin,
+                key,
+                message,
+            } if key.is_empty() => write!(f, "Invalid {}: {}", origin, message),
+            ConfigError::Invalid {
+                origin,
+                key,
+                message,
+            } => write!(f, "Invalid {} in {}: {}", key, origin, message),
+            ConfigError::UnknownProfile { name, available } if available.is_empty() => {
+                write!(
+                    f,
+                    "No profile '{}': there are no profiles in {}",
+                    name, CONFIG_FILE
+                )
+            }
+            ConfigError::UnknownProfile { name, available } => write!(
+                f,
+                "No profile '{}'. Available profiles: {}",
+                name,
+                available.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use crate::submit::Verdict;
+use std::error::Error;
+use std::fmt::Display;
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Where puzzle inputs are fetched from unless the `base_url` setting says
+/// otherwise.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP access `Downloader` needs, so tests can point it at a local server
+/// or replace it with a fake.
+pub trait Http {
+    fn get(
+        &self,
+        url: &str,
+    ) -> impl Future<Output = Result<Response, Box<dyn Error + Send + Sync>>> + Send;
+
+    /// Posts `form` URL-encoded, as the answer form on a puzzle page does.
+    fn post(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+    ) -> impl Future<Output = Result<Response, Box<dyn Error + Send + Sync>>> + Send;
+}
+
+/// `Http` over reqwest, sending the session cookie with every request.
+pub struct ReqwestHttp {
+    client: reqwest::Client,
+}
+
+impl ReqwestHttp {
+    pub fn new(session: &str) -> Result<Self, DownloadError> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        let cookie = format!("session={}", session);
+        let cookie = reqwest::header::HeaderValue::from_str(&cookie)
+            .map_err(|err| DownloadError::Http(err.into()))?;
+        headers.insert("cookie", cookie);
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(|err| DownloadError::Http(err.into()))?;
+        Ok(ReqwestHttp { client })
+    }
+}
+
+impl Http for ReqwestHttp {
+    async fn get(&self, url: &str) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        let response = self.client.get(url).send().await?;
+        let status = response.status().as_u16();
+        let body = response.text().await?;
+        Ok(Response { status, body })
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        let response = self.client.post(url).form(form).send().await?;
+        let status = response.status().as_u16();
+        let body = response.text().await?;
+        Ok(Response { status, body })
+    }
+}
+
+/// What the site answered for a day's input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Input(String),
+    /// The puzzle hasn't unlocked yet.
+    NotReady,
+}
+
+#[derive(Debug)]
+pub enum DownloadError {
+    /// No session cookie to authenticate with.
+    MissingToken,
+    Http(Box<dyn Error + Send + Sync>),
+    /// The site served its "please log in" page, so the session is missing or
+    /// has expired.
+    LoggedOut,
+    NotFound,
+    /// Still rate limited after backing off.
+    Throttled,
+    Status(u16),
+    /// Downloading would replace an input that's already there.
+    InputExists(PathBuf),
+    /// The answer page said something `Verdict::parse` doesn't recognise.
+    UnexpectedResponse,
+    Io(PathBuf, io::Error),
+}
+
+impl Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DownloadError::MissingToken => write!(
+                f,
+                "Missing session token: set TOKEN, or TOKEN_<PROFILE> for a profile, to your adventofcode.com session cookie, in the environment, .env or aoc.toml"
+            ),
+            DownloadError::Http(err) => write!(f, "Request failed: {}", err),
+            DownloadError::LoggedOut => write!(
+                f,
+                "Not logged in: the session token in use, from TOKEN, TOKEN_<PROFILE> or aoc.toml, is invalid or has expired"
+            ),
+            DownloadError::NotFound => write!(f, "No such puzzle"),
+            DownloadError::Throttled => write!(
+                f,
+                "Still rate limited after retrying, try again later"
+            ),
+            DownloadError::Status(status) => write!(f, "Server answered with status {}", status),
+            DownloadError::InputExists(path) => write!(
+                f,
+                "{} already exists, delete it first to download it again",
+                path.display()
+            ),
+            DownloadError::UnexpectedResponse => {
+                write!(f, "Could not make sense of the server's answer")
+            }
+            DownloadError::Io(path, err) => write!(f, "Could not write {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl Error for DownloadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DownloadError::Http(err) => Some(err.as_ref()),
+            DownloadError::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+pub struct Downloader<H> {
+    http: H,
+    base_url: String,
+    /// Minimum time between two requests, retries included.
+    interval: Duration,
+    /// Delay before the first retry of a throttled or failed request. It
+    /// doubles for each further retry.
+    backoff: Duration,
+    retries: u32,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl<H: Http> Downloader<H> {
+    pub fn new(http: H, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Downloader {
+            http,
+            base_url,
+            interval: Duration::from_secs(1),
+            backoff: Duration::from_secs(2),
+            retries: 3,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn with_backoff(mut self, backoff: Duration, retries: u32) -> Self {
+        self.backoff = backoff;
+        self.retries = retries;
+        self
+    }
+
+    pub fn input_url(&self, year: isize, day: isize) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn puzzle_url(&self, year: isize, day: isize) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    /// Fetches the input of `day` of `year`, retrying with backoff while the
+    /// server throttles or fails.
+    pub async fn download(&self, year: isize, day: isize) -> Result<Fetched, DownloadError> {
+        self.fetch(&self.input_url(year, day)).await
+    }
+
+    /// Fetches the HTML page describing `day` of `year`. It only includes part
+    /// two once part one is solved by the session's account.
+    pub async fn fetch_puzzle(&self, year: isize, day: isize) -> Result<Fetched, DownloadError> {
+        self.fetch(&self.puzzle_url(year, day)).await
+    }
+
+    async fn fetch(&self, url: &str) -> Result<Fetched, DownloadError> {
+        let mut backoff = self.backoff;
+
+        for attempt in 0..=self.retries {
+            if attempt > 0 {
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+
+            self.wait_turn().await;
+            let response = self.http.get(url).await.map_err(DownloadError::Http)?;
+
+            match classify(response) {
+                Err(DownloadError::Throttled | DownloadError::Status(500..=599))
+                    if attempt < self.retries => {}
+                result => return result,
+            }
+        }
+        unreachable!("the last attempt always returns")
+    }
+
+    /// Submits `answer` for `part` of `day`. Never retried, since a repeated
+    /// wrong answer only makes the site's timeout longer.
+    pub async fn submit(
+        &self,
+        year: isize,
+        day: isize,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, DownloadError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+
+        self.wait_turn().await;
+        let response = self
+            .http
+            .post(&url, &[("level", &level), ("answer", answer)])
+            .await
+            .map_err(DownloadError::Http)?;
+
+        let body = check_status(response)?;
+        Verdict::parse(&body).ok_or(DownloadError::UnexpectedResponse)
+    }
+
+    /// Downloads into `path`, refusing to replace an input that isn't empty.
+    /// Nothing is written unless the puzzle's input was actually served.
+    pub async fn download_to(
+        &self,
+        year: isize,
+        day: isize,
+        path: &Path,
+    ) -> Result<Fetched, DownloadError> {
+        match std::fs::metadata(path) {
+            Ok(metadata) if metadata.len() > 0 => {
+                return Err(DownloadError::InputExists(path.to_path_buf()))
+            }
+            _ => {}
+        }
+
+        let fetched = self.download(year, day).await?;
+        if let Fetched::Input(body) = &fetched {
+            let io_error = |err| DownloadError::Io(path.to_path_buf(), err);
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(io_error)?;
+            }
+            std::fs::write(path, body).map_err(io_error)?;
+        }
+        Ok(fetched)
+    }
+
+    /// Sleeps until `interval` has passed since the previous request.
+    async fn wait_turn(&self) {
+        let wait = {
+            let mut last_request = self.last_request.lock().unwrap();
+            let now = Instant::now();
+            let next = match *last_request {
+                Some(last) => (last + self.interval).max(now),
+                None => now,
+            };
+            *last_request = Some(next);
+            next - now
+        };
+        tokio::time::sleep(wait).await;
+    }
+}
+
+fn classify(response: Response) -> Result<Fetched, DownloadError> {
+    if response
+        .body
+        .contains("Please don't repeatedly request this endpoint")
+    {
+        return Ok(Fetched::NotReady);
+    }
+    check_status(response).map(Fetched::Input)
+}
+
+/// The body of a successful response, or what went wrong. The site answers
+/// some requests of a logged out session with its login page and a success
+/// status, so that's recognised by the page itself.
+fn check_status(response: Response) -> Result<String, DownloadError> {
+    if response.body.contains("Please log in") {
+        return Err(DownloadError::LoggedOut);
+    }
+
+    match response.status {
+        200..=299 => Ok(response.body),
+        400 | 401 | 403 => Err(DownloadError::LoggedOut),
+        404 => Err(DownloadError::NotFound),
+        429 => Err(DownloadError::Throttled),
+        status => Err(DownloadError::Status(status)),
+    }
+}
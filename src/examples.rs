@@ -0,0 +1,215 @@
+use crate::puzzle::{self, Node};
+use crate::solutions::{error_chain, Day, RawInput, Report};
+use std::error::Error;
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Examples for an event live in `puzzles/examples/YYYY`: one `NN-k` file per
+/// example block of day NN, and an `expected` table of what each part should
+/// answer. Unlike the cached pages next to them, they're checked in.
+pub fn examples_dir(year: isize) -> String {
+    format!("puzzles/examples/{}", year)
+}
+
+#[derive(Debug)]
+pub enum ExamplesError {
+    /// The puzzle page of the given day has no example blocks.
+    NoExamples(isize),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ExamplesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExamplesError::NoExamples(day) => write!(f, "No examples found for day {:02}", day),
+            ExamplesError::Io(dir, err) => {
+                write!(f, "Could not save examples in {}: {}", dir.display(), err)
+            }
+        }
+    }
+}
+
+impl Error for ExamplesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExamplesError::NoExamples(_) => None,
+            ExamplesError::Io(_, err) => Some(err),
+        }
+    }
+}
+
+pub struct Example {
+    /// File name, `NN-k` for the k-th example block on day NN's page.
+    pub name: String,
+    pub text: String,
+}
+
+/// One row of the `expected` table: `example<TAB>part<TAB>answer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expectation {
+    pub example: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+pub struct Extracted {
+    pub examples: Vec<Example>,
+    pub expectations: Vec<Expectation>,
+}
+
+/// Pulls the `<pre><code>` blocks and expected answers out of day `day`'s
+/// puzzle page.
+///
+/// The expected answer of a part is the last emphasised `<code>` of its
+/// article, which is how puzzles state the example's answer. Both parts are
+/// expected from the first block, since part two nearly always reuses part
+/// one's example; when it doesn't, the table is easy to fix by hand.
+pub fn extract(day: isize, html: &str) -> Extracted {
+    let articles = puzzle::articles(html);
+
+    let examples: Vec<Example> = articles
+        .iter()
+        .flat_map(|article| article.find_all("pre"))
+        .filter_map(|pre| pre.find_all("code").first().map(|code| code.text()))
+        .enumerate()
+        .map(|(i, text)| Example {
+            name: format!("{:02}-{}", day, i + 1),
+            text,
+        })
+        .collect();
+
+    let expectations = match examples.first() {
+        Some(example) => articles
+            .iter()
+            .zip(1..=2)
+            .filter_map(|(article, part)| {
+                Some(Expectation {
+                    example: example.name.clone(),
+                    part,
+                    answer: stated_answer(article)?,
+                })
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    Extracted {
+        examples,
+        expectations,
+    }
+}
+
+fn stated_answer(article: &Node) -> Option<String> {
+    article
+        .find_all("code")
+        .into_iter()
+        .rfind(|code| !code.find_all("em").is_empty())
+        .map(|code| code.text().trim().to_string())
+}
+
+pub fn load_table(dir: impl AsRef<Path>) -> io::Result<Vec<Expectation>> {
+    let contents = match std::fs::read_to_string(dir.as_ref().join("expected")) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+
+    Ok(contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            Some(Expectation {
+                example: fields.next()?.to_string(),
+                part: fields.next()?.parse().ok()?,
+                answer: fields.next()?.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Writes the examples of day `day` into `dir` and replaces its rows of the
+/// `expected` table, keeping every other day's.
+pub fn save(dir: impl AsRef<Path>, day: isize, extracted: &Extracted) -> io::Result<()> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+    for example in &extracted.examples {
+        std::fs::write(dir.join(&example.name), &example.text)?;
+    }
+
+    let prefix = format!("{:02}-", day);
+    let mut table: Vec<Expectation> = load_table(dir)?
+        .into_iter()
+        .filter(|row| !row.example.starts_with(&prefix))
+        .chain(extracted.expectations.iter().cloned())
+        .collect();
+    table.sort_by(|a, b| (&a.example, a.part).cmp(&(&b.example, b.part)));
+
+    let contents: String = table
+        .iter()
+        .map(|row| format!("{}\t{}\t{}\n", row.example, row.part, row.answer))
+        .collect();
+    std::fs::write(dir.join("expected"), contents)
+}
+
+/// Extracts day `day`'s examples from its puzzle page and saves them into
+/// `dir`. A page without any examples is an error rather than an empty table.
+pub fn extract_into(
+    dir: impl AsRef<Path>,
+    day: isize,
+    html: &str,
+) -> Result<Extracted, ExamplesError> {
+    let dir = dir.as_ref();
+    let extracted = extract(day, html);
+    if extracted.examples.is_empty() {
+        return Err(ExamplesError::NoExamples(day));
+    }
+    save(dir, day, &extracted).map_err(|err| ExamplesError::Io(dir.to_path_buf(), err))?;
+    Ok(extracted)
+}
+
+/// Solves `expectation`'s example from `dir` with `day` and checks the answer,
+/// describing the mismatch, error or panic otherwise.
+pub fn check(day: &Day, dir: impl AsRef<Path>, expectation: &Expectation) -> Result<(), String> {
+    let path = dir.as_ref().join(&expectation.example);
+    let input = RawInput::from_file(&path.to_string_lossy()).map_err(|err| err.to_string())?;
+
+    let answer = match day.solve(&input, expectation.part as isize) {
+        Ok(Report { parts, .. }) => match parts.into_iter().next() {
+            Some(part) => part.answer.map_err(|err| error_chain(&err))?,
+            None => return Err(format!("no part {}", expectation.part)),
+        },
+        Err(err) => return Err(err.to_string()),
+    };
+
+    if answer == expectation.answer {
+        Ok(())
+    } else {
+        Err(format!("expected {}, got {}", expectation.answer, answer))
+    }
+}
+
+/// Checks every example recorded in `dir` for `day`, returning how many there
+/// were or a line per failing one.
+pub fn check_day(day: &Day, dir: impl AsRef<Path>) -> Result<usize, Vec<String>> {
+    let dir = dir.as_ref();
+    let prefix = format!("{:02}-", day.number);
+    let table = load_table(dir).map_err(|err| vec![err.to_string()])?;
+    let rows: Vec<&Expectation> = table
+        .iter()
+        .filter(|row| row.example.starts_with(&prefix))
+        .collect();
+
+    let failures: Vec<String> = rows
+        .iter()
+        .filter_map(|row| {
+            let err = check(day, dir, row).err()?;
+            Some(format!("{} part {}: {}", row.example, row.part, err))
+        })
+        .collect();
+    if failures.is_empty() {
+        Ok(rows.len())
+    } else {
+        Err(failures)
+    }
+}
@@ -0,0 +1,147 @@
+use crate::config;
+use crate::submit::Verdict;
+use std::fmt::Display;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Submitted answers for one part of a day live in `guesses/YYYY/NN-P`, or
+/// under `guesses/<profile>` for a profile, one `verdict<TAB>answer` line per
+/// attempt, oldest first.
+pub fn guess_file(year: isize, day: isize, part: u8) -> String {
+    let dir = config::account_dir("guesses");
+    format!("{}/{}/{:02}-{}", dir.display(), year, day, part)
+}
+
+/// An answer the site has already judged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<Guess>> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+
+    Ok(contents
+        .lines()
+        .filter_map(|line| {
+            let (verdict, answer) = line.split_once('\t')?;
+            let verdict = match verdict {
+                "correct" => Verdict::Correct,
+                "too-high" => Verdict::TooHigh,
+                "too-low" => Verdict::TooLow,
+                "wrong" => Verdict::Wrong,
+                _ => return None,
+            };
+            Some(Guess {
+                answer: answer.to_string(),
+                verdict,
+            })
+        })
+        .collect())
+}
+
+/// Appends a judged answer to the log. Verdicts that don't judge the answer,
+/// like being rate limited, aren't worth remembering and are skipped.
+pub fn record(path: impl AsRef<Path>, answer: &str, verdict: &Verdict) -> io::Result<()> {
+    let verdict = match verdict {
+        Verdict::Correct => "correct",
+        Verdict::TooHigh => "too-high",
+        Verdict::TooLow => "too-low",
+        Verdict::Wrong => "wrong",
+        Verdict::RateLimited(_) | Verdict::AlreadySolved => return Ok(()),
+    };
+
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}\t{}", verdict, answer)
+}
+
+/// The earlier verdict on `answer`, if it was submitted before.
+pub fn previous<'a>(guesses: &'a [Guess], answer: &str) -> Option<&'a Guess> {
+    guesses.iter().find(|guess| guess.answer == answer)
+}
+
+/// Why an answer can't be right, judging by the guesses made so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conflict {
+    /// The same answer was already judged wrong.
+    Rejected(Verdict),
+    /// Not above `n`, which was too low.
+    NotAbove(i128),
+    /// Not below `n`, which was too high.
+    NotBelow(i128),
+    /// A different answer was already accepted.
+    NotCorrect(String),
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Conflict::Rejected(verdict) => write!(f, "already submitted: {}", verdict),
+            Conflict::NotAbove(n) => write!(f, "{} was already too low", n),
+            Conflict::NotBelow(n) => write!(f, "{} was already too high", n),
+            Conflict::NotCorrect(known) => write!(f, "{} was already correct", known),
+        }
+    }
+}
+
+/// The range a numeric answer must lie in: above every answer that was too
+/// low and below every answer that was too high.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bounds {
+    pub above: Option<i128>,
+    pub below: Option<i128>,
+}
+
+impl Bounds {
+    pub fn from_guesses(guesses: &[Guess]) -> Self {
+        let mut bounds = Bounds::default();
+        for guess in guesses {
+            let n = match guess.answer.parse::<i128>() {
+                Ok(n) => n,
+                Err(_) => continue,
+            };
+            match guess.verdict {
+                Verdict::TooLow => bounds.above = bounds.above.max(Some(n)),
+                Verdict::TooHigh => bounds.below = Some(bounds.below.map_or(n, |b| b.min(n))),
+                _ => {}
+            }
+        }
+        bounds
+    }
+}
+
+/// Checks `answer` against earlier guesses without asking the site. Once an
+/// answer was correct, every other one is wrong. Otherwise answers that aren't
+/// numbers can only conflict by having been rejected before.
+pub fn check(guesses: &[Guess], answer: &str) -> Option<Conflict> {
+    if let Some(known) = guesses
+        .iter()
+        .find(|guess| guess.verdict == Verdict::Correct && guess.answer != answer)
+    {
+        return Some(Conflict::NotCorrect(known.answer.clone()));
+    }
+    if let Some(guess) = previous(guesses, answer) {
+        return match guess.verdict {
+            Verdict::Correct => None,
+            ref verdict => Some(Conflict::Rejected(verdict.clone())),
+        };
+    }
+
+    let n: i128 = answer.parse().ok()?;
+    let bounds = Bounds::from_guesses(guesses);
+    match (bounds.above, bounds.below) {
+        (Some(above), _) if n <= above => Some(Conflict::NotAbove(above)),
+        (_, Some(below)) if n >= below => Some(Conflict::NotBelow(below)),
+        _ => None,
+    }
+}
@@ -0,0 +1,33 @@
+//! Advent of Code solutions as a library, for any number of events.
+//!
+//! Every day lives in `solutions::yYYYY::dayNN` and implements [`Solver`], so
+//! its input can be parsed and solved directly:
+//!
+//! ```
+//! use hello_rust::solutions::y2021::day01;
+//! use hello_rust::Solver;
+//!
+//! let input = day01::Problem.read_str("199\n200\n208\n").unwrap();
+//! assert_eq!(day01::Problem.solve_first(&input).unwrap(), 2);
+//! ```
+//!
+//! [`find`] looks a day up by year and number in the registry, and [`all`]
+//! lists every registered day of every event, which is what the `hello-rust`
+//! binary runs.
+
+pub mod answers;
+pub mod calendar;
+pub mod config;
+pub mod download;
+pub mod examples;
+pub mod guesses;
+pub mod output;
+pub mod puzzle;
+pub mod runner;
+pub mod scaffold;
+pub mod solutions;
+pub mod submit;
+
+pub use solutions::{
+    all, find, Day, InputError, ParseError, RawInput, Report, Solver, SolverError, UnknownDay,
+};
@@ -1,14 +1,25 @@
-use chrono::Datelike;
-use clap::{App, Arg, SubCommand};
-use std::io::Read;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use hello_rust::calendar::{self, SystemClock};
+use hello_rust::config::{self, Color, Config, Section, Settings};
+use hello_rust::download::{DownloadError, Downloader, Fetched, ReqwestHttp};
+use hello_rust::runner::PartOutcome;
+use hello_rust::scaffold::{self, Preset, ScaffoldError};
+use hello_rust::solutions::get_elapsed;
+use hello_rust::solutions::memory::{self, CountingAllocator};
+use hello_rust::submit::Submission;
+use hello_rust::{answers, examples, puzzle, runner, solutions, Day};
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-mod solutions;
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
 
-    let matches = App::new("Advent of Code 2021 Solutions")
+    let matches = App::new("Advent of Code Solutions")
         .arg(Arg::with_name("part"))
         .arg(
             Arg::with_name("day")
@@ -16,86 +27,585 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .long("day")
                 .takes_value(true),
         )
-        .subcommand(SubCommand::with_name("download").arg(Arg::with_name("day")))
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .takes_value(true)
+                .possible_values(&["text", "json", "csv"])
+                .global(true)
+                .help("Defaults to text, unless aoc.toml or AOC_FORMAT says otherwise"),
+        )
+        .arg(
+            Arg::with_name("year")
+                .short("y")
+                .long("year")
+                .takes_value(true)
+                .global(true)
+                .help("Which event's puzzles to run"),
+        )
+        .arg(
+            Arg::with_name("profile")
+                .long("profile")
+                .takes_value(true)
+                .global(true)
+                .help("Uses the token and inputs of a profile from aoc.toml"),
+        )
+        .arg(
+            Arg::with_name("inputs")
+                .long("inputs")
+                .takes_value(true)
+                .value_name("DIR")
+                .global(true)
+                .help("Where puzzle inputs are kept, in a subdirectory per profile"),
+        )
+        .arg(
+            Arg::with_name("color")
+                .long("color")
+                .takes_value(true)
+                .possible_values(&["auto", "always", "never"])
+                .global(true),
+        )
+        .arg(
+            Arg::with_name("input")
+                .short("i")
+                .long("input")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("Reads the puzzle input from PATH, or from stdin if PATH is -"),
+        )
+        .arg(
+            Arg::with_name("all")
+                .short("a")
+                .long("all")
+                .conflicts_with_all(&["day", "input"])
+                .help("Solves every day that has an input file"),
+        )
+        .arg(timeout_arg())
+        .arg(memory_arg())
+        .arg(
+            Arg::with_name("jobs")
+                .short("j")
+                .long("jobs")
+                .takes_value(true)
+                .value_name("N")
+                .help("Solves up to N days at a time, and both parts of a day at once"),
+        )
+        .subcommand(
+            SubCommand::with_name("download")
+                .arg(Arg::with_name("day"))
+                .arg(
+                    Arg::with_name("wait")
+                        .long("wait")
+                        .help("Sleeps until the puzzle unlocks, tomorrow's unless a day is given"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("submit")
+                .about("Submits an answer, computing it with the day's solver if none is given")
+                .arg(
+                    Arg::with_name("day")
+                        .short("d")
+                        .long("day")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("part")
+                        .short("p")
+                        .long("part")
+                        .takes_value(true)
+                        .possible_values(&["1", "2"])
+                        .required(true),
+                )
+                .arg(Arg::with_name("answer")),
+        )
+        .subcommand(
+            SubCommand::with_name("describe")
+                .about("Prints the puzzle text, fetching the page unless it's cached")
+                .arg(
+                    Arg::with_name("day")
+                        .short("d")
+                        .long("day")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("markdown")
+                        .long("markdown")
+                        .help("Renders Markdown instead of terminal text"),
+                )
+                .arg(
+                    Arg::with_name("refresh")
+                        .long("refresh")
+                        .help("Fetches the page again, say to see part two once it unlocks"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("examples")
+                .about(
+                    "Saves the examples and expected answers from the puzzle page under puzzles/examples/",
+                )
+                .arg(
+                    Arg::with_name("day")
+                        .short("d")
+                        .long("day")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("html")
+                        .long("html")
+                        .takes_value(true)
+                        .value_name("PATH")
+                        .help("Reads a saved puzzle page instead of the cached or fetched one"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("new")
+                .about("Generates a solver for a day, parsing its input like an earlier one")
+                .arg(
+                    Arg::with_name("day")
+                        .short("d")
+                        .long("day")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("preset")
+                        .long("preset")
+                        .takes_value(true)
+                        .possible_values(&scaffold::PRESETS)
+                        .default_value("lines-int")
+                        .help("The shape of the input: one of the parsers earlier days used"),
+                ),
+        )
+        .subcommand(SubCommand::with_name("list").about("Lists the days that have a solver"))
+        .subcommand(
+            SubCommand::with_name("verify")
+                .about("Checks answers against the ones recorded under answers/")
+                .arg(Arg::with_name("day").help("Defaults to every day with an input file"))
+                .arg(timeout_arg())
+                .arg(
+                    Arg::with_name("record")
+                        .long("record")
+                        .help("Writes the current answers into the store"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("bench")
+                .about("Times parsing and both parts over repeated runs")
+                .arg(Arg::with_name("day").help("Defaults to every day with an input file"))
+                .arg(
+                    Arg::with_name("iterations")
+                        .short("n")
+                        .long("iterations")
+                        .takes_value(true)
+                        .default_value("10"),
+                )
+                .arg(
+                    Arg::with_name("warmup")
+                        .short("w")
+                        .long("warmup")
+                        .takes_value(true)
+                        .default_value("2"),
+                )
+                .arg(memory_arg()),
+        )
         .get_matches();
 
+    // `--memory` may come before `bench` as well as after it, but only
+    // solving and benchmarking report memory.
+    let memory = matches.is_present("memory")
+        || matches
+            .subcommand_matches("bench")
+            .is_some_and(|bench| bench.is_present("memory"));
+    if memory {
+        if let Some(name) = matches.subcommand_name().filter(|name| *name != "bench") {
+            eprintln!("--memory doesn't apply to {}", name);
+            std::process::exit(1);
+        }
+        memory::enable();
+    }
+
+    let settings = or_exit(settings(&matches));
+    config::activate(&settings);
+    let year = settings.year;
+    let token = settings.token.as_deref();
+
     if let Some(matches) = matches.subcommand_matches("download") {
-        return download(get_day(matches.value_of("day"))).await;
+        let wait = matches.is_present("wait");
+        // Tomorrow's puzzle may belong to another year than the configured
+        // one, so the clock picks both.
+        let (year, day) = match (matches.value_of("day"), wait) {
+            (None, true) => or_exit(calendar::next_day(&SystemClock)),
+            (day, _) => (year, get_day(day)),
+        };
+        // Only waiting needs to know when the day unlocks; otherwise the
+        // server is the one to say whether there's such a puzzle.
+        let until = match wait {
+            true => or_exit(calendar::wait_for(&SystemClock, year, day)),
+            false => None,
+        };
+        if let Err(err) = download(year, day, until, token).await {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return Ok(());
     }
 
-    let part: isize = matches.value_of("part").unwrap_or("3").parse()?;
-    let day = get_day(matches.value_of("day"));
-    solutions::solve(day, part);
+    if let Some(matches) = matches.subcommand_matches("submit") {
+        let day = get_day(matches.value_of("day"));
+        let part = matches.value_of("part").unwrap().parse()?;
+        if let Err(err) = submit(year, day, part, matches.value_of("answer"), token).await {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
-    Ok(())
-}
+    if let Some(matches) = matches.subcommand_matches("describe") {
+        let day = get_day(matches.value_of("day"));
+        let style = if matches.is_present("markdown") {
+            puzzle::Style::Markdown
+        } else if settings.color == Color::Always
+            || settings.color == Color::Auto && std::io::stdout().is_terminal()
+        {
+            puzzle::Style::Text
+        } else {
+            puzzle::Style::Plain
+        };
+        if let Err(err) = describe(year, day, style, matches.is_present("refresh"), token).await {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
-async fn download(selected_day: isize) -> Result<(), Box<dyn std::error::Error>> {
-    let mut headers = reqwest::header::HeaderMap::new();
+    if let Some(matches) = matches.subcommand_matches("examples") {
+        let day = get_day(matches.value_of("day"));
+        if let Err(err) = extract_examples(year, day, matches.value_of("html"), token).await {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
-    let token = std::env::var("TOKEN");
-    if let Err(_) = token {
-        panic!("Missing TOKEN env variable");
+    if let Some(matches) = matches.subcommand_matches("new") {
+        let day = get_day(matches.value_of("day"));
+        let preset = or_exit(matches.value_of("preset").unwrap().parse());
+        let path = or_exit(scaffold_day(year, day, preset));
+        println!("Wrote {}", path.display());
+        return Ok(());
     }
-    let cookie = format!("session={}", token?);
-    headers.insert("cookie", reqwest::header::HeaderValue::from_str(&cookie)?);
 
-    let client = reqwest::Client::builder()
-        .default_headers(headers)
-        .build()?;
+    if matches.subcommand_matches("list").is_some() {
+        for day in solutions::year(year) {
+            let input = if std::path::Path::new(&day.input_file()).exists() {
+                ""
+            } else {
+                "  (no input)"
+            };
+            println!("{:>3}  {}{}", day.number, day.title, input);
+        }
+        return Ok(());
+    }
 
-    let body = client
-        .get(format!(
-            "https://adventofcode.com/2021/day/{}/input",
-            selected_day
-        ))
-        .send()
-        .await?
-        .text()
-        .await?;
+    if let Some(matches) = matches.subcommand_matches("verify") {
+        let days = match matches.value_of("day") {
+            Some(day) => vec![find_day(year, parse_day(day))],
+            None => runner::days_with_input(year),
+        };
+        let timeout = or_exit(get_timeout(matches));
+        if !answers::verify(&days, matches.is_present("record"), timeout)? {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
-    if body.contains("Please don't repeatedly request this endpoint") {
-        println!("Day {:02} not yet ready", selected_day);
+    if let Some(matches) = matches.subcommand_matches("bench") {
+        let days = match matches.value_of("day") {
+            Some(day) => vec![find_day(year, parse_day(day))],
+            None => runner::days_with_input(year),
+        };
+        let iterations = matches.value_of("iterations").unwrap();
+        let warmup = matches.value_of("warmup").unwrap();
+        let options = solutions::BenchOptions {
+            iterations: or_exit(
+                iterations
+                    .parse()
+                    .ok()
+                    .filter(|iterations| *iterations > 0)
+                    .ok_or_else(|| format!("Invalid number of iterations '{}'", iterations)),
+            ),
+            warmup: or_exit(
+                warmup
+                    .parse()
+                    .map_err(|_| format!("Invalid number of warmup runs '{}'", warmup)),
+            ),
+        };
+        runner::bench_days(&days, &options, settings.format);
         return Ok(());
     }
 
-    std::fs::write(format!("inputs/{:02}", selected_day), body)?;
+    let jobs = match matches.value_of("jobs") {
+        Some(jobs) => or_exit(
+            jobs.parse()
+                .ok()
+                .filter(|jobs| *jobs > 0)
+                .ok_or_else(|| format!("Invalid number of jobs '{}'", jobs)),
+        ),
+        None => 1,
+    };
+    let options = solutions::SolveOptions {
+        parts: matches.value_of("part").unwrap_or("3").parse()?,
+        timeout: or_exit(get_timeout(&matches)),
+        parallel: jobs > 1,
+    };
+    let format = settings.format;
+    if matches.is_present("all") {
+        std::process::exit(runner::run_all(year, &options, jobs, format));
+    }
 
-    copy_template(selected_day)?;
+    let day = find_day(year, get_day(matches.value_of("day")));
+    let input = match matches.value_of("input") {
+        Some("-") => solutions::RawInput::from_stdin(),
+        Some(path) => solutions::RawInput::from_file(path),
+        None => solutions::RawInput::from_file(&day.input_file()),
+    };
+    std::process::exit(runner::run_day(day, &options, input, format));
+}
+
+async fn download(
+    year: isize,
+    selected_day: isize,
+    until_unlock: Option<Duration>,
+    token: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let downloader = downloader(token)?;
+
+    if let Some(until) = until_unlock {
+        println!(
+            "Day {:02} unlocks in {}, waiting",
+            selected_day,
+            get_elapsed(until)
+        );
+        // A little slack, in case our clock is ahead of the server's.
+        tokio::time::sleep(until + Duration::from_secs(2)).await;
+    }
 
-    append_day(selected_day)?;
+    let path = solutions::input_file(year, selected_day);
+    match downloader
+        .download_to(year, selected_day, Path::new(&path))
+        .await?
+    {
+        Fetched::Input(_) => println!("Saved {}", path),
+        Fetched::NotReady => {
+            println!("Day {:02} not yet ready", selected_day);
+            return Ok(());
+        }
+    }
+
+    // The registry picks up new day files on its own, so scaffolding is just
+    // writing one. Never clobber a day that already has a solver.
+    if solutions::find(year, selected_day).is_err() {
+        scaffold_day(year, selected_day, Preset::LinesInt)?;
+        println!(
+            "Scaffolded day {:02}, run `describe --day {}` to read the puzzle",
+            selected_day, selected_day
+        );
+    }
 
     Ok(())
 }
 
-fn copy_template(selected_day: isize) -> Result<u64, std::io::Error> {
-    return std::fs::copy(
-        "src/solutions/template.rs",
-        format!("src/solutions/day{:02}.rs", selected_day),
+async fn submit(
+    year: isize,
+    selected_day: isize,
+    part: u8,
+    answer: Option<&str>,
+    token: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let answer = match answer {
+        Some(answer) => answer.to_string(),
+        None => {
+            let day = find_day(year, selected_day);
+            match runner::run_part(day, part as isize, None) {
+                Some(PartOutcome::Solved(answer, ..)) => answer,
+                Some(PartOutcome::Failed(failure, _)) => {
+                    return Err(format!("Could not compute an answer: {}", failure).into())
+                }
+                None => return Err(format!("Day {:02} has no part {}", selected_day, part).into()),
+            }
+        }
+    };
+
+    let submission = Submission::new(year, selected_day, part, answer);
+    submission.check()?;
+    let downloader = downloader(token)?;
+    println!(
+        "Submitting {} for day {:02} part {}",
+        submission.answer, selected_day, part
     );
+    println!("{}", submission.send(&downloader).await?);
+    Ok(())
 }
 
-fn append_day(selected_day: isize) -> Result<(), std::io::Error> {
-    let mut file = std::fs::File::open("src/solutions/mod.rs")?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
+async fn describe(
+    year: isize,
+    selected_day: isize,
+    style: puzzle::Style,
+    refresh: bool,
+    token: Option<&str>,
+) -> Result<(), DownloadError> {
+    if let Some(html) = puzzle_page(year, selected_day, refresh, token).await? {
+        print!("{}", puzzle::render(&puzzle::articles(&html), style));
+    }
+    Ok(())
+}
+
+/// The cached puzzle page of a day, or `None` if it isn't unlocked yet.
+async fn puzzle_page(
+    year: isize,
+    selected_day: isize,
+    refresh: bool,
+    token: Option<&str>,
+) -> Result<Option<String>, DownloadError> {
+    let path = puzzle::page_file(year, selected_day);
+    let html = puzzle::cached_page(path, year, selected_day, refresh, || downloader(token)).await?;
+    if html.is_none() {
+        println!("Day {:02} not yet ready", selected_day);
+    }
+    Ok(html)
+}
 
-    let new_mod = format!("mod day{:02};", selected_day);
-    contents = contents.replace("mod solver;", &format!("{}\nmod solver;", new_mod));
+async fn extract_examples(
+    year: isize,
+    selected_day: isize,
+    html_file: Option<&str>,
+    token: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let html = match html_file {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|err| format!("Could not read {}: {}", path, err))?,
+        None => match puzzle_page(year, selected_day, false, token).await? {
+            Some(html) => html,
+            None => return Ok(()),
+        },
+    };
 
-    let new_match = format!(
-        "{} => day{:02}::Problem.solve(filename, parts),",
-        selected_day, selected_day
-    );
-    contents = contents.replace(
-        "_ => panic!",
-        &format!("{}\n        _ => panic!", new_match),
-    );
+    let dir = examples::examples_dir(year);
+    let extracted = examples::extract_into(&dir, selected_day, &html)?;
+    for example in &extracted.examples {
+        println!("Wrote {}/{}", dir, example.name);
+    }
+    for row in &extracted.expectations {
+        println!("{} part {}: {}", row.example, row.part, row.answer);
+    }
+    Ok(())
+}
+
+/// A downloader logged in with the session `token`.
+fn downloader(token: Option<&str>) -> Result<Downloader<ReqwestHttp>, DownloadError> {
+    let token = token.ok_or(DownloadError::MissingToken)?;
+    Ok(Downloader::new(
+        ReqwestHttp::new(token)?,
+        config::base_url(),
+    ))
+}
 
-    return std::fs::write("src/solutions/mod.rs", contents);
+/// Writes a solver for a day, titled from its cached puzzle page if there is
+/// one.
+fn scaffold_day(
+    year: isize,
+    selected_day: isize,
+    preset: Preset,
+) -> Result<PathBuf, ScaffoldError> {
+    let title = std::fs::read_to_string(puzzle::page_file(year, selected_day))
+        .ok()
+        .and_then(|html| puzzle::title(&puzzle::articles(&html)))
+        .unwrap_or_default();
+    scaffold::create(year, selected_day, preset, &title)
 }
 
+/// Resolves the settings from the config file, the environment and the flags
+/// given to the command, in increasing order of precedence.
+fn settings(matches: &ArgMatches) -> Result<Settings, Box<dyn std::error::Error>> {
+    // Global flags are only filled in on the subcommand they were given to.
+    let args = matches.subcommand().1.unwrap_or(matches);
+
+    let config = Config::load(config::locate().as_deref())?;
+    let profile = args
+        .value_of("profile")
+        .map(str::to_string)
+        .or_else(|| std::env::var("AOC_PROFILE").ok())
+        .or_else(|| config.profile.clone());
+    let flags = Section {
+        token: None,
+        year: match args.value_of("year") {
+            Some(year) => Some(
+                year.parse()
+                    .map_err(|_| format!("Invalid year '{}'", year))?,
+            ),
+            None => None,
+        },
+        inputs: args
+            .value_of("inputs")
+            .map(|dir| config::account_path(dir, profile.as_deref())),
+        format: args.value_of("format").map(str::parse).transpose()?,
+        color: args.value_of("color").map(str::parse).transpose()?,
+        base_url: None,
+    };
+    let env = Section::from_env(profile.as_deref())?;
+    Ok(config.resolve(profile.as_deref(), flags.or(env))?)
+}
+
+/// The day given on the command line, or today's puzzle in US Eastern time.
 fn get_day(day: Option<&str>) -> isize {
-    let default_day = chrono::Utc::now().day().to_string();
-    day.unwrap_or(&default_day).parse().unwrap()
+    match day {
+        Some(day) => parse_day(day),
+        None => or_exit(calendar::current_day(&SystemClock)),
+    }
+}
+
+/// A day given on the command line, exiting if it isn't a number.
+fn parse_day(day: &str) -> isize {
+    or_exit(day.parse().map_err(|_| format!("Invalid day '{}'", day)))
+}
+
+fn memory_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("memory")
+        .long("memory")
+        .help("Counts allocations, bytes allocated and peak memory of each phase")
+}
+
+fn timeout_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("timeout")
+        .short("t")
+        .long("timeout")
+        .takes_value(true)
+        .value_name("DURATION")
+        .help("Gives up on a part after DURATION, such as 30s, 500ms or 2m")
+}
+
+/// The `--timeout` limit, if one was given.
+fn get_timeout(matches: &ArgMatches) -> Result<Option<Duration>, String> {
+    matches
+        .value_of("timeout")
+        .map(solutions::parse_timeout)
+        .transpose()
+}
+
+fn or_exit<T>(result: Result<T, impl std::fmt::Display>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    })
+}
+
+/// Looks up day `number` of `year` in the registry, exiting with the list of
+/// available days if there's no solver for it.
+fn find_day(year: isize, number: isize) -> &'static Day {
+    match solutions::find(year, number) {
+        Ok(day) => day,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
 }
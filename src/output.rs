@@ -0,0 +1,118 @@
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format '{}', expected text, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+/// Columns of the records emitted when solving days. Each day has a `parse`
+/// phase before its parts, and solving a whole year ends with records that
+/// have a `total` instead of a day and phase.
+pub const SOLVE_COLUMNS: [&str; 13] = [
+    "year",
+    "day",
+    "part",
+    "phase",
+    "total",
+    "answer",
+    "error",
+    "error_kind",
+    "duration_ns",
+    "allocations",
+    "allocated_bytes",
+    "peak_bytes",
+    "input_hash",
+];
+
+/// Columns of the records emitted when benchmarking days.
+pub const BENCH_COLUMNS: [&str; 12] = [
+    "year",
+    "day",
+    "phase",
+    "min_ns",
+    "median_ns",
+    "mean_ns",
+    "stddev_ns",
+    "allocations",
+    "allocated_bytes",
+    "peak_bytes",
+    "error",
+    "input_hash",
+];
+
+/// Prints `rows` as a JSON array of objects keyed by `columns`, or as CSV with
+/// a header line. Text output is printed by each command itself.
+pub fn print_records(format: Format, columns: &[&str], rows: &[Vec<Value>]) {
+    match format {
+        Format::Text => {}
+        Format::Json => {
+            let records = json_records(columns, rows);
+            println!("{}", serde_json::to_string_pretty(&records).unwrap());
+        }
+        Format::Csv => {
+            println!("{}", columns.join(","));
+            for row in rows {
+                let cells: Vec<String> = row.iter().map(csv_cell).collect();
+                println!("{}", cells.join(","));
+            }
+        }
+    }
+}
+
+/// `rows` as JSON objects keyed by `columns`, with the keys in the order of
+/// `columns` so they line up with the CSV output.
+pub fn json_records(columns: &[&str], rows: &[Vec<Value>]) -> Vec<Value> {
+    rows.iter()
+        .map(|row| {
+            let object: Map<String, Value> = columns
+                .iter()
+                .map(|column| column.to_string())
+                .zip(row.iter().cloned())
+                .collect();
+            Value::Object(object)
+        })
+        .collect()
+}
+
+/// A CSV cell for `value`: empty for null, and quoted when it holds a comma,
+/// a quote or a line break, `\r` included.
+pub fn csv_cell(value: &Value) -> String {
+    let text = match value {
+        Value::Null => return String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+/// FNV-1a hash of an input, so records can tell which input produced them.
+pub fn input_hash(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
@@ -0,0 +1,373 @@
+//! Puzzle pages: caching them, and just enough HTML parsing to pull out the
+//! `<article>` sections that hold the puzzle text and render them.
+
+use crate::config;
+use crate::download::{DownloadError, Downloader, Fetched, Http};
+use std::path::Path;
+
+/// Puzzle pages for a day are cached in `puzzles/YYYY/NN.html`, or under
+/// `puzzles/<profile>` for a profile: what a page shows depends on the
+/// account, as part two only appears once part one is solved.
+pub fn page_file(year: isize, day: isize) -> String {
+    let dir = config::account_dir("puzzles");
+    format!("{}/{}/{:02}.html", dir.display(), year, day)
+}
+
+/// The puzzle page of `day` of `year` cached in `path`, fetched and cached
+/// first if there isn't one yet or `refresh` is set. The downloader is only
+/// made when the page has to be fetched, so reading the cache needs no
+/// session. Returns `None` if the puzzle isn't unlocked yet.
+pub async fn cached_page<H: Http>(
+    path: impl AsRef<Path>,
+    year: isize,
+    day: isize,
+    refresh: bool,
+    downloader: impl FnOnce() -> Result<Downloader<H>, DownloadError>,
+) -> Result<Option<String>, DownloadError> {
+    let path = path.as_ref();
+    if !refresh {
+        if let Ok(html) = std::fs::read_to_string(path) {
+            return Ok(Some(html));
+        }
+    }
+
+    let html = match downloader()?.fetch_puzzle(year, day).await? {
+        Fetched::Input(html) => html,
+        Fetched::NotReady => return Ok(None),
+    };
+
+    let io_error = |err| DownloadError::Io(path.to_path_buf(), err);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    std::fs::write(path, &html).map_err(io_error)?;
+    Ok(Some(html))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Text(String),
+    Element {
+        name: String,
+        href: Option<String>,
+        children: Vec<Node>,
+    },
+}
+
+impl Node {
+    /// The text inside this node, tags dropped.
+    pub fn text(&self) -> String {
+        match self {
+            Node::Text(text) => text.clone(),
+            Node::Element { children, .. } => children.iter().map(Node::text).collect(),
+        }
+    }
+
+    pub fn is(&self, tag: &str) -> bool {
+        matches!(self, Node::Element { name, .. } if name == tag)
+    }
+
+    pub fn children(&self) -> &[Node] {
+        match self {
+            Node::Text(_) => &[],
+            Node::Element { children, .. } => children,
+        }
+    }
+
+    /// Every element named `tag` under this node, outermost first and in
+    /// document order.
+    pub fn find_all<'a>(&'a self, tag: &str) -> Vec<&'a Node> {
+        let mut found = Vec::new();
+        self.collect(tag, &mut found);
+        found
+    }
+
+    fn collect<'a>(&'a self, tag: &str, found: &mut Vec<&'a Node>) {
+        if self.is(tag) {
+            found.push(self);
+        }
+        for child in self.children() {
+            child.collect(tag, found);
+        }
+    }
+}
+
+/// Tags that never have a closing tag.
+const VOID: [&str; 6] = ["br", "hr", "img", "input", "link", "meta"];
+
+/// Parses `html` into a tree under a synthetic root element. Tolerant of what
+/// it doesn't need: comments, doctypes and scripts are skipped, unmatched
+/// closing tags ignored and unclosed ones closed at the end.
+pub fn parse(html: &str) -> Node {
+    let mut stack = vec![(String::new(), None, Vec::new())];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let next_tag = match rest.find('<') {
+            Some(i) => i,
+            None => rest.len(),
+        };
+        if next_tag > 0 {
+            let text = decode_entities(&rest[..next_tag]);
+            stack.last_mut().unwrap().2.push(Node::Text(text));
+            rest = &rest[next_tag..];
+            continue;
+        }
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let end = match rest.find('>') {
+            Some(end) => end,
+            None => break,
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_lowercase();
+            if let Some(open) = stack.iter().rposition(|(open, _, _)| *open == name) {
+                while stack.len() > open.max(1) {
+                    close(&mut stack);
+                }
+            }
+            continue;
+        }
+
+        let name: String = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        if name == "script" || name == "style" {
+            let closing = format!("</{}", name);
+            rest = rest.find(&closing).map_or("", |end| &rest[end..]);
+            continue;
+        }
+
+        let href = attribute(tag, "href");
+        if tag.ends_with('/') || VOID.contains(&name.as_str()) {
+            stack.last_mut().unwrap().2.push(Node::Element {
+                name,
+                href,
+                children: Vec::new(),
+            });
+        } else {
+            stack.push((name, href, Vec::new()));
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    let (name, href, children) = stack.pop().unwrap();
+    Node::Element {
+        name,
+        href,
+        children,
+    }
+}
+
+type Open = (String, Option<String>, Vec<Node>);
+
+fn close(stack: &mut Vec<Open>) {
+    let (name, href, children) = stack.pop().unwrap();
+    stack.last_mut().unwrap().2.push(Node::Element {
+        name,
+        href,
+        children,
+    });
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{}=", name))? + name.len() + 1;
+    let value = &tag[start..];
+    let value = match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next()?,
+        _ => value.split(|c: char| c.is_whitespace()).next()?,
+    };
+    Some(decode_entities(value))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+
+        let character = entity.and_then(|(entity, _)| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = entity.strip_prefix('#')?;
+                let code = match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => code.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+        match (character, entity) {
+            (Some(character), Some((_, end))) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The `<article>` sections of a puzzle page, one per part that's unlocked.
+pub fn articles(html: &str) -> Vec<Node> {
+    parse(html)
+        .find_all("article")
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+/// The puzzle's name, from a `--- Day 1: Sonar Sweep ---` heading.
+pub fn title(articles: &[Node]) -> Option<String> {
+    let heading = articles.first()?.find_all("h2").first()?.text();
+    let title = heading.trim().trim_matches('-').trim();
+    let (_, title) = title.split_once(": ")?;
+    Some(title.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Text for the terminal, with emphasis in bold.
+    Text,
+    /// Text without any escape codes, for when output isn't a terminal.
+    Plain,
+    Markdown,
+}
+
+/// Renders puzzle articles as `style`, blocks separated by blank lines.
+pub fn render(articles: &[Node], style: Style) -> String {
+    let mut out = String::new();
+    for article in articles {
+        for node in article.children() {
+            render_block(node, style, &mut out);
+        }
+    }
+    out.trim_end().to_string() + "\n"
+}
+
+fn render_block(node: &Node, style: Style, out: &mut String) {
+    match node {
+        Node::Text(text) if text.trim().is_empty() => {}
+        _ if node.is("h2") => {
+            if style == Style::Markdown {
+                out.push_str("## ");
+            }
+            out.push_str(node.text().trim());
+            out.push_str("\n\n");
+        }
+        _ if node.is("pre") => {
+            let text = node.text();
+            match style {
+                Style::Text | Style::Plain => {
+                    for line in text.lines() {
+                        out.push_str("    ");
+                        out.push_str(line);
+                        out.push('\n');
+                    }
+                }
+                Style::Markdown => {
+                    out.push_str("```\n");
+                    out.push_str(&text);
+                    if !text.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n");
+                }
+            }
+            out.push('\n');
+        }
+        _ if node.is("ul") => {
+            for item in node.children().iter().filter(|child| child.is("li")) {
+                out.push_str(match style {
+                    Style::Markdown => "- ",
+                    _ => "  - ",
+                });
+                out.push_str(render_inline(item.children(), style).trim());
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+        _ => {
+            let text = render_inline(std::slice::from_ref(node), style);
+            if !text.trim().is_empty() {
+                out.push_str(text.trim());
+                out.push_str("\n\n");
+            }
+        }
+    }
+}
+
+fn render_inline(nodes: &[Node], style: Style) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&collapse_whitespace(text)),
+            Node::Element { name, href, .. } => {
+                let inner = render_inline(node.children(), style);
+                match (name.as_str(), style) {
+                    ("em", Style::Text) => out.push_str(&format!("\x1b[1m{}\x1b[0m", inner)),
+                    ("em", Style::Markdown) => out.push_str(&format!("**{}**", inner)),
+                    ("code", Style::Markdown) => {
+                        // Markdown has no emphasis inside code spans, so it
+                        // goes around the span instead.
+                        let code = format!("`{}`", node.text());
+                        if node.find_all("em").is_empty() {
+                            out.push_str(&code);
+                        } else {
+                            out.push_str(&format!("**{}**", code));
+                        }
+                    }
+                    ("a", Style::Markdown) => match href {
+                        Some(href) => out.push_str(&format!("[{}]({})", inner, href)),
+                        None => out.push_str(&inner),
+                    },
+                    ("br", _) => out.push('\n'),
+                    _ => out.push_str(&inner),
+                }
+            }
+        }
+    }
+    out
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_space {
+                collapsed.push(' ');
+            }
+            last_space = true;
+        } else {
+            collapsed.push(c);
+            last_space = false;
+        }
+    }
+    collapsed
+}
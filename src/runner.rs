@@ -0,0 +1,572 @@
+use crate::guesses;
+use crate::output::{self, Format, BENCH_COLUMNS, SOLVE_COLUMNS};
+use crate::solutions::memory::{self, Usage};
+use crate::solutions::{
+    self, error_chain, get_elapsed, BenchOptions, Day, InputError, Panic, RawInput, Report,
+    SolveOptions, SolverError,
+};
+use serde_json::{json, Value};
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One line of the summary table. Cells are (answer, time) for part 1 and part 2.
+struct Row {
+    day: isize,
+    cells: [(String, String); 2],
+    failed: bool,
+}
+
+/// How a single part went when run on its own, panics included.
+pub enum PartOutcome {
+    /// The answer, how long it took and what it allocated, if counted.
+    Solved(String, Duration, Option<Usage>),
+    Failed(Failure, Option<Duration>),
+}
+
+/// Why a part has no answer.
+#[derive(Debug, Clone)]
+pub enum Failure {
+    /// The input couldn't be read or parsed.
+    Input(String),
+    /// The parser panicked.
+    ParsePanicked(Panic),
+    Solver(SolverError),
+}
+
+impl From<InputError> for Failure {
+    fn from(err: InputError) -> Self {
+        match err {
+            InputError::Panicked(_, panic) => Failure::ParsePanicked(panic),
+            err => Failure::Input(err.to_string()),
+        }
+    }
+}
+
+impl Failure {
+    /// A short name for the kind of failure, for machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Failure::Input(_) => "input",
+            Failure::ParsePanicked(_) => "panic",
+            Failure::Solver(err) => err.kind(),
+        }
+    }
+
+    /// Whether the part had nothing to solve, like day 25's second. Those
+    /// aren't failures of the run: they leave the exit code and the table
+    /// alone.
+    pub fn is_not_implemented(&self) -> bool {
+        matches!(self, Failure::Solver(SolverError::NotImplemented))
+    }
+
+    /// The process exit code for a run that failed this way: 2 for input
+    /// errors, 3 to 7 for solver errors and 8 for panics.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Failure::Input(_) => 2,
+            Failure::ParsePanicked(_) => 8,
+            Failure::Solver(err) => err.exit_code(),
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Input(message) => write!(f, "{}", message),
+            Failure::ParsePanicked(panic) => write!(f, "parsing {}", panic),
+            Failure::Solver(err) => write!(f, "{}", error_chain(err)),
+        }
+    }
+}
+
+/// Registered days of `year` that have an input file to solve.
+pub fn days_with_input(year: isize) -> Vec<&'static Day> {
+    solutions::year(year)
+        .filter(|day| Path::new(&day.input_file()).exists())
+        .collect()
+}
+
+/// Runs one part of `day` for at most `timeout`, turning input errors, panics
+/// and timeouts into `Failed`. Returns `None` if the solver didn't report that
+/// part.
+pub fn run_part(day: &Day, part: isize, timeout: Option<Duration>) -> Option<PartOutcome> {
+    let options = SolveOptions {
+        timeout,
+        ..SolveOptions::parts(part)
+    };
+    let outcome = match RawInput::from_file(&day.input_file())
+        .and_then(|input| day.solve_with(&input, &options))
+    {
+        Ok(Report { parts, .. }) => {
+            let report = parts.into_iter().next()?;
+            match report.answer {
+                Ok(answer) => PartOutcome::Solved(answer, report.elapsed, report.memory),
+                Err(err) => PartOutcome::Failed(Failure::Solver(err), Some(report.elapsed)),
+            }
+        }
+        Err(err) => PartOutcome::Failed(err.into(), None),
+    };
+    Some(outcome)
+}
+
+/// Runs both parts of `day` for at most `timeout` each, parsing its input
+/// once for both. A part is `None` if the solver didn't report it.
+pub fn run_parts(day: &Day, timeout: Option<Duration>) -> [Option<PartOutcome>; 2] {
+    let options = SolveOptions {
+        timeout,
+        ..SolveOptions::parts(3)
+    };
+    solve_day(day, &options).parts
+}
+
+/// Solves one day against `input` and prints its report in `format`. Returns
+/// the exit code of the first part that failed, or 0. Parts that aren't
+/// implemented don't count as failed.
+pub fn run_day(
+    day: &Day,
+    options: &SolveOptions,
+    input: Result<RawInput, InputError>,
+    format: Format,
+) -> i32 {
+    let hash = input
+        .as_ref()
+        .ok()
+        .map(|input| output::input_hash(&input.text));
+    // Earlier guesses only say something about answers to the day's own input.
+    let own_input = matches!(&input, Ok(input) if input.name == day.input_file());
+    let result = input.and_then(|input| day.solve_with(&input, options));
+    if let (Ok(report), true) = (&result, own_input) {
+        for part in &report.parts {
+            if let Ok(answer) = &part.answer {
+                warn_if_known_wrong(day, part.part, answer);
+            }
+        }
+    }
+    let result = result.map_err(|err| (err.to_string(), Failure::from(err)));
+    let exit_code = match &result {
+        Ok(report) => report
+            .parts
+            .iter()
+            .filter_map(|part| part.answer.as_ref().err())
+            .find(|err| !matches!(err, SolverError::NotImplemented))
+            .map_or(0, SolverError::exit_code),
+        Err((_, failure)) => failure.exit_code(),
+    };
+    if format == Format::Text {
+        match result {
+            Ok(report) => print!("{}", report),
+            Err((message, _)) => println!("{}", message),
+        }
+        return exit_code;
+    }
+
+    let rows = match result {
+        Ok(report) => {
+            let parsed = (Some(report.parse_elapsed), report.parse_memory);
+            let parse = solve_record(day, None, Ok(None), parsed, &hash);
+            std::iter::once(parse)
+                .chain(report.parts.into_iter().map(|part| {
+                    let answer = part.answer.map(Some).map_err(Failure::Solver);
+                    let measured = (Some(part.elapsed), part.memory);
+                    solve_record(day, Some(part.part), answer, measured, &hash)
+                }))
+                .collect()
+        }
+        Err((_, failure)) => vec![solve_record(day, None, Err(failure), (None, None), &hash)],
+    };
+    output::print_records(format, &SOLVE_COLUMNS, &rows);
+    exit_code
+}
+
+/// Solves every day of `year` that has an input file and prints a summary table,
+/// solving up to `jobs` days at a time. Rows come out in day order however the
+/// days get scheduled.
+///
+/// A part that errors or panics only flags its row and the rest of the
+/// calendar still gets solved. Returns the exit code of the first part that
+/// failed, or 0; parts that aren't implemented don't count as failed.
+pub fn run_all(year: isize, options: &SolveOptions, jobs: usize, format: Format) -> i32 {
+    let start = Instant::now();
+
+    let days = days_with_input(year);
+    let outcomes = in_parallel(&days, jobs, |day| solve_day(day, options));
+    let days: Vec<(&Day, DayOutcome)> = days.into_iter().zip(outcomes).collect();
+    // Parts that ran side by side and days solved by several jobs overlap, so
+    // their times add up to more than the wall clock.
+    let summed: Duration = days.iter().map(|(_, outcome)| outcome.elapsed()).sum();
+
+    for (day, outcome) in &days {
+        for (i, outcome) in outcome.parts.iter().enumerate() {
+            if let Some(PartOutcome::Solved(answer, ..)) = outcome {
+                warn_if_known_wrong(day, i as u8 + 1, answer);
+            }
+        }
+    }
+    let exit_code = days
+        .iter()
+        .flat_map(|(_, outcome)| &outcome.parts)
+        .find_map(|outcome| match outcome {
+            Some(PartOutcome::Failed(failure, _)) if !failure.is_not_implemented() => {
+                Some(failure.exit_code())
+            }
+            _ => None,
+        })
+        .unwrap_or(0);
+
+    if format != Format::Text {
+        let mut rows: Vec<Vec<Value>> = days
+            .iter()
+            .flat_map(|(day, outcome)| {
+                let hash = file_hash(day);
+                let parse = outcome.parse.as_ref().map(|parsed| {
+                    let measured = (Some(parsed.elapsed), parsed.memory);
+                    solve_record(day, None, Ok(None), measured, &hash)
+                });
+                let parts = outcome.parts.iter().enumerate().filter_map(|(i, outcome)| {
+                    let part = Some(i as u8 + 1);
+                    Some(match outcome.as_ref()? {
+                        PartOutcome::Solved(answer, elapsed, memory) => {
+                            let answer = Ok(Some(answer.clone()));
+                            solve_record(day, part, answer, (Some(*elapsed), *memory), &hash)
+                        }
+                        PartOutcome::Failed(failure, elapsed) => {
+                            solve_record(day, part, Err(failure.clone()), (*elapsed, None), &hash)
+                        }
+                    })
+                });
+                parse.into_iter().chain(parts).collect::<Vec<_>>()
+            })
+            .collect();
+        rows.push(total_record(year, "wall_clock", start.elapsed()));
+        rows.push(total_record(year, "summed_over_days", summed));
+        output::print_records(format, &SOLVE_COLUMNS, &rows);
+        return exit_code;
+    }
+
+    let rows: Vec<Row> = days
+        .into_iter()
+        .map(|(day, outcome)| table_row(day.number, outcome.parts))
+        .collect();
+    print_table(&rows);
+    println!(
+        "Total: {} wall clock, {} summed over days",
+        get_elapsed(start.elapsed()),
+        get_elapsed(summed)
+    );
+    exit_code
+}
+
+/// Runs `job` on each of `items`, up to `jobs` at a time, and returns the
+/// results in the order of `items`.
+fn in_parallel<T: Sync, R: Send>(items: &[T], jobs: usize, job: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    if jobs <= 1 {
+        return items.iter().map(job).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(items.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        match items.get(i) {
+                            Some(item) => done.push((i, job(item))),
+                            None => return done,
+                        }
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            for (i, result) in worker.join().expect("jobs catch their own panics") {
+                results[i] = Some(result);
+            }
+        }
+    });
+    results.into_iter().map(|result| result.unwrap()).collect()
+}
+
+/// Benchmarks each of `days` and prints their timing statistics.
+pub fn bench_days(days: &[&Day], options: &BenchOptions, format: Format) {
+    let mut rows = Vec::new();
+
+    for day in days {
+        let input = RawInput::from_file(&day.input_file());
+        let hash = input
+            .as_ref()
+            .ok()
+            .map(|input| output::input_hash(&input.text));
+        let result = input.and_then(|input| day.bench(&input, options));
+        if format == Format::Text {
+            println!("Day {:02}: {}", day.number, day.title);
+            match result {
+                Ok(report) => print!("{}", report),
+                Err(err) => println!("  {}", first_line(&err.to_string())),
+            }
+            continue;
+        }
+
+        match result {
+            Ok(report) => rows.extend(report.phases.into_iter().map(|phase| {
+                let nanos = |f: fn(&solutions::Stats) -> Duration| {
+                    phase
+                        .stats
+                        .as_ref()
+                        .ok()
+                        .map(|stats| f(stats).as_nanos() as u64)
+                };
+                let mut row = vec![
+                    json!(day.year),
+                    json!(day.number),
+                    json!(phase.phase),
+                    json!(nanos(|s| s.min)),
+                    json!(nanos(|s| s.median)),
+                    json!(nanos(|s| s.mean)),
+                    json!(nanos(|s| s.stddev)),
+                ];
+                row.extend(usage_cells(phase.memory));
+                row.extend([json!(phase.stats.as_ref().err()), json!(hash)]);
+                row
+            })),
+            Err(err) => rows.push(vec![
+                json!(day.year),
+                json!(day.number),
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                Value::Null,
+                json!(err.to_string()),
+                json!(hash),
+            ]),
+        }
+    }
+
+    output::print_records(format, &BENCH_COLUMNS, &rows);
+}
+
+/// Warns on stderr when `answer` contradicts what submitting earlier guesses
+/// taught us, so it doesn't get submitted again.
+fn warn_if_known_wrong(day: &Day, part: u8, answer: &str) {
+    let log = guesses::guess_file(day.year, day.number, part);
+    let conflict = guesses::load(log)
+        .ok()
+        .and_then(|logged| guesses::check(&logged, answer));
+    if let Some(conflict) = conflict {
+        eprintln!(
+            "Warning: day {:02} part {} answer {} is wrong, {}",
+            day.number, part, answer, conflict
+        );
+    }
+}
+
+fn file_hash(day: &Day) -> Option<String> {
+    let input = RawInput::from_file(&day.input_file()).ok()?;
+    Some(output::input_hash(&input.text))
+}
+
+/// A record of how a part went, or parsing when there's no `part`. Parsing
+/// has no answer. `measured` is how long it took and what it allocated, as
+/// far as either is known.
+fn solve_record(
+    day: &Day,
+    part: Option<u8>,
+    answer: Result<Option<String>, Failure>,
+    measured: (Option<Duration>, Option<Usage>),
+    hash: &Option<String>,
+) -> Vec<Value> {
+    let (elapsed, memory) = measured;
+    let phase = part.map_or("parse".to_string(), |part| format!("part {}", part));
+    let mut record = vec![
+        json!(day.year),
+        json!(day.number),
+        json!(part),
+        json!(phase),
+        Value::Null,
+        json!(answer.as_ref().ok().and_then(Option::as_ref)),
+        json!(answer.as_ref().err().map(Failure::to_string)),
+        json!(answer.as_ref().err().map(Failure::kind)),
+        json!(elapsed.map(|elapsed| elapsed.as_nanos() as u64)),
+    ];
+    record.extend(usage_cells(memory));
+    record.push(json!(hash));
+    record
+}
+
+/// A record of how long a whole run took, by the measure `total` names.
+fn total_record(year: isize, total: &str, elapsed: Duration) -> Vec<Value> {
+    vec![
+        json!(year),
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        json!(total),
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        json!(elapsed.as_nanos() as u64),
+        Value::Null,
+        Value::Null,
+        Value::Null,
+        Value::Null,
+    ]
+}
+
+/// The allocations, allocated bytes and peak bytes columns.
+fn usage_cells(memory: Option<Usage>) -> [Value; 3] {
+    [
+        json!(memory.map(|usage| usage.allocations)),
+        json!(memory.map(|usage| usage.bytes)),
+        json!(memory.map(|usage| usage.peak)),
+    ]
+}
+
+/// How solving a whole day went.
+struct DayOutcome {
+    /// Set once the input was parsed.
+    parse: Option<Parsed>,
+    parts: [Option<PartOutcome>; 2],
+}
+
+impl DayOutcome {
+    /// The time spent parsing and solving, whether or not the parts ran at
+    /// the same time.
+    fn elapsed(&self) -> Duration {
+        let parts = self
+            .parts
+            .iter()
+            .flatten()
+            .filter_map(|outcome| match outcome {
+                PartOutcome::Solved(_, elapsed, _) => Some(*elapsed),
+                PartOutcome::Failed(_, elapsed) => *elapsed,
+            });
+        self.parse
+            .iter()
+            .map(|parsed| parsed.elapsed)
+            .chain(parts)
+            .sum()
+    }
+}
+
+/// What parsing a day's input took.
+struct Parsed {
+    elapsed: Duration,
+    memory: Option<Usage>,
+}
+
+/// Solves the selected parts of `day`, parsing its input once for both.
+fn solve_day(day: &Day, options: &SolveOptions) -> DayOutcome {
+    let result =
+        RawInput::from_file(&day.input_file()).and_then(|input| day.solve_with(&input, options));
+    let mut outcomes = [None, None];
+    let mut parse = None;
+    match result {
+        Ok(report) => {
+            parse = Some(Parsed {
+                elapsed: report.parse_elapsed,
+                memory: report.parse_memory,
+            });
+            for part in report.parts {
+                outcomes[part.part as usize - 1] = Some(match part.answer {
+                    Ok(answer) => PartOutcome::Solved(answer, part.elapsed, part.memory),
+                    Err(err) => PartOutcome::Failed(Failure::Solver(err), Some(part.elapsed)),
+                });
+            }
+        }
+        Err(err) => {
+            let failure = Failure::from(err);
+            for (i, outcome) in outcomes.iter_mut().enumerate() {
+                if options.parts & (1 << i) != 0 {
+                    *outcome = Some(PartOutcome::Failed(failure.clone(), None));
+                }
+            }
+        }
+    }
+    DayOutcome {
+        parse,
+        parts: outcomes,
+    }
+}
+
+fn table_row(day: isize, outcomes: [Option<PartOutcome>; 2]) -> Row {
+    let mut failed = false;
+    let cells = outcomes.map(|outcome| match outcome {
+        Some(PartOutcome::Solved(answer, elapsed, memory)) => {
+            let mut time = get_elapsed(elapsed);
+            if let Some(usage) = memory {
+                time.push_str(&format!(", {} peak", memory::get_size(usage.peak)));
+            }
+            (answer, time)
+        }
+        Some(PartOutcome::Failed(failure, elapsed)) => {
+            failed |= !failure.is_not_implemented();
+            (
+                first_line(&failure.to_string()),
+                elapsed.map(get_elapsed).unwrap_or_default(),
+            )
+        }
+        None => ("-".to_string(), String::new()),
+    });
+
+    Row { day, cells, failed }
+}
+
+fn first_line(text: &str) -> String {
+    text.lines().next().unwrap_or_default().to_string()
+}
+
+fn print_table(rows: &[Row]) {
+    let header = ["Part 1", "Time", "Part 2", "Time"];
+    let mut widths = header.map(|h| h.chars().count());
+    for row in rows {
+        let [(a1, t1), (a2, t2)] = &row.cells;
+        for (width, cell) in widths.iter_mut().zip([a1, t1, a2, t2]) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    println!(
+        "  Day | {:<w0$} | {:>w1$} | {:<w2$} | {:>w3$}",
+        header[0],
+        header[1],
+        header[2],
+        header[3],
+        w0 = widths[0],
+        w1 = widths[1],
+        w2 = widths[2],
+        w3 = widths[3],
+    );
+    println!(
+        "------+-{}-+-{}-+-{}-+-{}",
+        "-".repeat(widths[0]),
+        "-".repeat(widths[1]),
+        "-".repeat(widths[2]),
+        "-".repeat(widths[3]),
+    );
+    for row in rows {
+        let [(a1, t1), (a2, t2)] = &row.cells;
+        println!(
+            "{} {:>3} | {:<w0$} | {:>w1$} | {:<w2$} | {:>w3$}",
+            if row.failed { "!" } else { " " },
+            row.day,
+            a1,
+            t1,
+            a2,
+            t2,
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+}
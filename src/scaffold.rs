@@ -0,0 +1,238 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// How a new day's input gets parsed, after the shapes earlier days needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preset {
+    /// One integer per line.
+    LinesInt,
+    /// A rectangle of digits, like day 9.
+    GridU8,
+    /// One line of comma-separated numbers, like day 6.
+    CsvUsize,
+    /// Groups of lines separated by blank lines, like day 13.
+    Blocks,
+    /// A struct per line, matched with a regex, like day 22.
+    Regex,
+}
+
+pub const PRESETS: [&str; 5] = ["lines-int", "grid-u8", "csv-usize", "blocks", "regex"];
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lines-int" => Ok(Preset::LinesInt),
+            "grid-u8" => Ok(Preset::GridU8),
+            "csv-usize" => Ok(Preset::CsvUsize),
+            "blocks" => Ok(Preset::Blocks),
+            "regex" => Ok(Preset::Regex),
+            _ => Err(format!(
+                "unknown preset '{}', expected one of {}",
+                s,
+                PRESETS.join(", ")
+            )),
+        }
+    }
+}
+
+/// Solvers live in `src/solutions/yYYYY/dayNN.rs`, where `build.rs` registers
+/// them.
+pub fn day_file(year: isize, day: isize) -> PathBuf {
+    PathBuf::from(format!("src/solutions/y{}/day{:02}.rs", year, day))
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day already has a solver, which scaffolding never replaces.
+    Exists(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => {
+                write!(f, "{} already exists, not replacing it", path.display())
+            }
+            ScaffoldError::Io(path, err) => {
+                write!(f, "Could not write {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Exists(_) => None,
+            ScaffoldError::Io(_, err) => Some(err),
+        }
+    }
+}
+
+/// Writes a new solver for `day` of `year`, refusing to replace one that's
+/// already there. Returns where it was written.
+pub fn create(
+    year: isize,
+    day: isize,
+    preset: Preset,
+    title: &str,
+) -> Result<PathBuf, ScaffoldError> {
+    let path = day_file(year, day);
+    let write = || {
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        file.write_all(generate(year, day, preset, title).as_bytes())
+    };
+    match write() {
+        Ok(()) => Ok(path),
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Err(ScaffoldError::Exists(path)),
+        Err(err) => Err(ScaffoldError::Io(path, err)),
+    }
+}
+
+/// The source of a day module: the preset's `Input` type and parser, parts
+/// not implemented yet, and a test running the day's recorded examples.
+pub fn generate(year: isize, day: isize, preset: Preset, title: &str) -> String {
+    let (imports, input, read, items) = match preset {
+        Preset::LinesInt => (
+            "use crate::solutions::input::{parse_lines, parse_token};\n\
+             use crate::solutions::{ParseError, Solver, SolverError};\n\
+             use std::io::BufRead;\n",
+            "Vec<isize>",
+            "        parse_lines(file_reader, |line| parse_token(line, line, \"an integer\"))\n",
+            "",
+        ),
+        Preset::GridU8 => (
+            "use ndarray::Array2;\n\
+             \n\
+             use crate::solutions::input::parse_digit_grid;\n\
+             use crate::solutions::{ParseError, Solver, SolverError};\n\
+             use std::io::BufRead;\n",
+            "Array2<u8>",
+            "        parse_digit_grid(file_reader)\n",
+            "",
+        ),
+        Preset::CsvUsize => (
+            "use crate::solutions::input::{parse_token, read_lines};\n\
+             use crate::solutions::{ParseError, Solver, SolverError};\n\
+             use std::io::BufRead;\n",
+            "Vec<usize>",
+            "        let lines = read_lines(file_reader)?;\n\
+             \x20       let line = lines\n\
+             \x20           .first()\n\
+             \x20           .ok_or_else(|| ParseError::end_of_input(\"a list of numbers\").on_line(1))?;\n\
+             \n\
+             \x20       line.split(',')\n\
+             \x20           .map(|number| parse_token(line, number, \"a number\"))\n\
+             \x20           .collect::<Result<_, _>>()\n\
+             \x20           .map_err(|err| err.on_line(1))\n",
+            "",
+        ),
+        Preset::Blocks => (
+            "use crate::solutions::input::read_lines;\n\
+             use crate::solutions::{ParseError, Solver, SolverError};\n\
+             use std::io::BufRead;\n",
+            "Vec<Vec<String>>",
+            "        let lines = read_lines(file_reader)?;\n\
+             \n\
+             \x20       Ok(lines\n\
+             \x20           .split(|line| line.is_empty())\n\
+             \x20           .filter(|block| !block.is_empty())\n\
+             \x20           .map(|block| block.to_vec())\n\
+             \x20           .collect())\n",
+            "",
+        ),
+        Preset::Regex => (
+            "use crate::solutions::input::{parse_lines, parse_token};\n\
+             use crate::solutions::{ParseError, Solver, SolverError};\n\
+             use regex::Regex;\n\
+             use std::io::BufRead;\n\
+             use std::str::FromStr;\n",
+            "Vec<Entry>",
+            "        parse_lines(file_reader, str::parse)\n",
+            "\n\
+             #[derive(Debug)]\n\
+             pub struct Entry {\n\
+             \x20   pub name: String,\n\
+             \x20   pub value: isize,\n\
+             }\n\
+             \n\
+             impl FromStr for Entry {\n\
+             \x20   type Err = ParseError;\n\
+             \n\
+             \x20   fn from_str(s: &str) -> Result<Self, Self::Err> {\n\
+             \x20       lazy_static::lazy_static! {\n\
+             \x20           static ref LINE_RGX: Regex = Regex::new(r\"^(\\w+) (-?\\d+)$\").unwrap();\n\
+             \x20       }\n\
+             \n\
+             \x20       let captures = LINE_RGX\n\
+             \x20           .captures(s)\n\
+             \x20           .ok_or_else(|| ParseError::new(s, \"'<name> <value>'\"))?;\n\
+             \n\
+             \x20       Ok(Entry {\n\
+             \x20           name: captures[1].to_string(),\n\
+             \x20           value: parse_token(s, &captures[2], \"an integer\")?,\n\
+             \x20       })\n\
+             \x20   }\n\
+             }\n",
+        ),
+    };
+
+    format!(
+        "{imports}\n\
+         pub const TITLE: &str = {title:?};\n\
+         \n\
+         pub struct Problem;\n\
+         \n\
+         impl Solver for Problem {{\n\
+         \x20   type Input = {input};\n\
+         \x20   type Output1 = usize;\n\
+         \x20   type Output2 = usize;\n\
+         \n\
+         \x20   fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {{\n\
+         {read}\
+         \x20   }}\n\
+         \n\
+         \x20   fn solve_first(&self, _input: &Self::Input) -> Result<Self::Output1, SolverError> {{\n\
+         \x20       Err(SolverError::NotImplemented)\n\
+         \x20   }}\n\
+         \n\
+         \x20   fn solve_second(&self, _input: &Self::Input) -> Result<Self::Output2, SolverError> {{\n\
+         \x20       Err(SolverError::NotImplemented)\n\
+         \x20   }}\n\
+         }}\n\
+         {items}\
+         \n\
+         #[cfg(test)]\n\
+         mod tests {{\n\
+         \x20   use crate::{{examples, solutions}};\n\
+         \n\
+         \x20   /// Runs the examples `examples --day {day}` saved from the puzzle page.\n\
+         \x20   #[test]\n\
+         \x20   fn solves_examples() {{\n\
+         \x20       let day = solutions::find({year}, {day}).unwrap();\n\
+         \x20       let dir = concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/puzzles/examples/{year}\");\n\
+         \x20       if let Err(failures) = examples::check_day(day, dir) {{\n\
+         \x20           panic!(\"{{}}\", failures.join(\"\\n\"));\n\
+         \x20       }}\n\
+         \x20   }}\n\
+         }}\n",
+        imports = imports,
+        title = title,
+        input = input,
+        read = read,
+        items = items,
+        year = year,
+        day = day,
+    )
+}
@@ -0,0 +1,174 @@
+use super::memory::{self, Usage};
+use super::panics;
+use super::solver::{error_chain, get_elapsed};
+use super::{InputError, RawInput, Solver};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+pub struct BenchOptions {
+    /// Measured runs of each phase. At least one is always measured.
+    pub iterations: usize,
+    pub warmup: usize,
+}
+
+/// Summary of one phase's timings over every measured iteration.
+#[derive(Debug, Clone)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+/// Timings for parsing, part 1 or part 2, or why that phase couldn't be measured.
+#[derive(Debug, Clone)]
+pub struct PhaseBench {
+    pub phase: &'static str,
+    pub stats: Result<Stats, String>,
+    /// What the last run allocated, when allocations are being counted.
+    pub memory: Option<Usage>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub phases: Vec<PhaseBench>,
+}
+
+/// Times `read_input`, `solve_first` and `solve_second` separately, running each
+/// `options.warmup` times before measuring `options.iterations` runs.
+pub fn bench<S: Solver + ?Sized>(
+    solver: &S,
+    raw: &RawInput,
+    options: &BenchOptions,
+) -> Result<BenchReport, InputError> {
+    let input = solver.parse(raw)?;
+
+    let phases = vec![
+        measure("parse", options, || {
+            solver
+                .read_str(&raw.text)
+                .map(|_| ())
+                .map_err(|err| err.to_string())
+        }),
+        measure("part 1", options, || {
+            solver
+                .solve_first(&input)
+                .map(|_| ())
+                .map_err(|err| error_chain(&err))
+        }),
+        measure("part 2", options, || {
+            solver
+                .solve_second(&input)
+                .map(|_| ())
+                .map_err(|err| error_chain(&err))
+        }),
+    ];
+    Ok(BenchReport { phases })
+}
+
+fn measure(
+    phase: &'static str,
+    options: &BenchOptions,
+    mut run: impl FnMut() -> Result<(), String>,
+) -> PhaseBench {
+    let iterations = options.iterations.max(1);
+    let mut samples = Vec::with_capacity(iterations);
+    let mut memory = None;
+
+    for i in 0..options.warmup + iterations {
+        let start = Instant::now();
+        let (result, usage) = memory::measure(|| panics::catch(&mut run));
+        let elapsed = start.elapsed();
+        memory = usage;
+
+        let failure = match result {
+            Ok(Ok(())) => None,
+            Ok(Err(err)) => Some(format!("error: {}", err)),
+            Err(panic) => Some(panic.to_string()),
+        };
+        if let Some(failure) = failure {
+            return PhaseBench {
+                phase,
+                stats: Err(failure),
+                memory: None,
+            };
+        }
+
+        if i >= options.warmup {
+            samples.push(elapsed);
+        }
+    }
+
+    PhaseBench {
+        phase,
+        stats: Ok(Stats::from_samples(samples)),
+        memory,
+    }
+}
+
+impl Stats {
+    /// Summarises the timings of a phase. Panics without any.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let nanos = samples.iter().map(|s| s.as_nanos() as f64);
+        let mean = nanos.clone().sum::<f64>() / n as f64;
+        let variance = nanos.map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Stats {
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let counted = self.phases.iter().any(|phase| phase.memory.is_some());
+        write!(
+            f,
+            "  {:<6} {:>10} {:>10} {:>10} {:>10}",
+            "phase", "min", "median", "mean", "stddev"
+        )?;
+        if counted {
+            write!(f, " {:>8} {:>11} {:>11}", "allocs", "allocated", "peak")?;
+        }
+        writeln!(f)?;
+        for phase in &self.phases {
+            match &phase.stats {
+                Ok(stats) => {
+                    write!(
+                        f,
+                        "  {:<6} {:>10} {:>10} {:>10} {:>10}",
+                        phase.phase,
+                        get_elapsed(stats.min),
+                        get_elapsed(stats.median),
+                        get_elapsed(stats.mean),
+                        get_elapsed(stats.stddev)
+                    )?;
+                    if let Some(usage) = &phase.memory {
+                        write!(
+                            f,
+                            " {:>8} {:>11} {:>11}",
+                            usage.allocations,
+                            memory::get_size(usage.bytes),
+                            memory::get_size(usage.peak)
+                        )?;
+                    }
+                    writeln!(f)?
+                }
+                Err(err) => writeln!(f, "  {:<6} {}", phase.phase, err)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+//! Time limits for parts. With a limit, each part runs on a worker thread
+//! that's given up on once the limit passes. Long searches can poll
+//! [`checkpoint`] or [`cancelled`] to stop cleanly when that happens, rather
+//! than running on in the background until the process exits.
+
+use super::memory;
+use super::solver::{run_part, Answer, PartReport, Report, Solver, SolverError};
+use super::{InputError, Panic, RawInput};
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a cancelled part gets to notice and stop before it's abandoned.
+const GRACE: Duration = Duration::from_millis(100);
+
+/// Tells a running part that it's out of time.
+#[derive(Debug, Clone)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    limit: Duration,
+}
+
+impl CancelToken {
+    fn new(limit: Duration) -> Self {
+        CancelToken {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            limit,
+        }
+    }
+
+    /// The token of the part running on this thread, if it has a time limit.
+    /// Hand it to any threads the part starts so they can poll it too.
+    pub fn current() -> Option<CancelToken> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// A timeout error once cancelled, so a solver can bail out with `?`.
+    pub fn check(&self) -> Answer<()> {
+        if self.is_cancelled() {
+            Err(SolverError::Timeout(self.limit))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Whether the part running on this thread has run out of time. Always false
+/// without a time limit.
+pub fn cancelled() -> bool {
+    CURRENT.with(|current| {
+        current
+            .borrow()
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+    })
+}
+
+/// Returns a timeout error once the part running on this thread has run out
+/// of time. Cheap enough to call on every step of a search.
+pub fn checkpoint() -> Answer<()> {
+    CURRENT.with(|current| match current.borrow().as_ref() {
+        Some(token) => token.check(),
+        None => Ok(()),
+    })
+}
+
+/// Parses `raw` and solves the selected `parts`, each on its own thread and
+/// for at most `limit`. A part that runs out of time is reported as a timeout
+/// and the other part still runs, at the same time when `parallel`.
+pub(super) fn solve_within<S>(
+    solver: &'static S,
+    raw: &RawInput,
+    parts: isize,
+    limit: Duration,
+    parallel: bool,
+) -> Result<Report, InputError>
+where
+    S: Solver + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    let start = Instant::now();
+    let (input, parse_memory) = memory::measure(|| solver.parse(raw));
+    let input = Arc::new(input?);
+
+    let mut report = Report {
+        parse_elapsed: start.elapsed(),
+        parse_memory,
+        parts: Vec::new(),
+    };
+    let mut running = Vec::new();
+    if parts & 0x1 > 0 {
+        let input = Arc::clone(&input);
+        running.push(Running::start(1, limit, move || solver.solve_first(&input)));
+    }
+    if !parallel {
+        report.parts.extend(running.drain(..).map(Running::finish));
+    }
+    if parts & 0x2 > 0 {
+        let input = Arc::clone(&input);
+        running.push(Running::start(2, limit, move || {
+            solver.solve_second(&input)
+        }));
+    }
+    report
+        .parts
+        .extend(running.into_iter().map(Running::finish));
+    Ok(report)
+}
+
+/// A part started on a worker thread.
+struct Running {
+    part: u8,
+    token: CancelToken,
+    receiver: Receiver<PartReport>,
+    start: Instant,
+}
+
+impl Running {
+    fn start<T: Display>(
+        part: u8,
+        limit: Duration,
+        solve: impl FnOnce() -> Answer<T> + Send + 'static,
+    ) -> Running {
+        let token = CancelToken::new(limit);
+        let (sender, receiver) = mpsc::channel();
+
+        let worker_token = token.clone();
+        thread::spawn(move || {
+            CURRENT.with(|current| *current.borrow_mut() = Some(worker_token));
+            // Nobody is listening any more if the part ran out of time.
+            let _ = sender.send(run_part(part, solve));
+        });
+
+        Running {
+            part,
+            token,
+            receiver,
+            start: Instant::now(),
+        }
+    }
+
+    /// Waits for the part's answer until its time is up.
+    fn finish(self) -> PartReport {
+        let limit = self.token.limit;
+        let left = limit.saturating_sub(self.start.elapsed());
+        let answer = match self.receiver.recv_timeout(left) {
+            Ok(report) => return report,
+            Err(RecvTimeoutError::Timeout) => {
+                self.token.cancel();
+                // A solver that polls the token stops on its own; one that
+                // doesn't is left to run until the process exits.
+                let _ = self.receiver.recv_timeout(GRACE);
+                Err(SolverError::Timeout(limit))
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(SolverError::Panicked(Panic {
+                message: "the part's thread stopped without an answer".to_string(),
+                location: None,
+            })),
+        };
+        PartReport {
+            part: self.part,
+            answer,
+            elapsed: self.start.elapsed(),
+            memory: None,
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use super::panics::Panic;
+use ndarray::Array2;
+use std::error::Error;
+use std::fmt::Display;
+use std::io::{BufRead, Read};
+use std::str::FromStr;
+
+/// Describes where an input file stopped making sense and what was expected there.
+///
+/// `line` and `column` are 1-based. Errors produced by `FromStr` impls don't know
+/// their line yet (`line == 0`) until `read_input` attaches it with `on_line`.
+/// `end_of_input` is set when the file ran out, as opposed to a line that's
+/// merely empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+    pub end_of_input: bool,
+}
+
+impl ParseError {
+    /// Error for a whole line of text that doesn't have the expected shape.
+    pub fn new(text: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column: 1,
+            text: text.to_string(),
+            expected: expected.into(),
+            end_of_input: false,
+        }
+    }
+
+    /// Error for `token`, which must be a slice of `line`. The column is derived
+    /// from the token's position inside the line.
+    pub fn at(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let start = line.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= line.len())
+            .unwrap_or(0);
+
+        ParseError {
+            column: line[..offset].chars().count() + 1,
+            ..ParseError::new(line, expected)
+        }
+    }
+
+    /// Error for a file that ended before something it needed.
+    pub fn end_of_input(expected: impl Into<String>) -> Self {
+        ParseError {
+            end_of_input: true,
+            ..ParseError::new("", expected)
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        if self.end_of_input {
+            return write!(
+                f,
+                "{}:{}: expected {}, found end of input",
+                file, self.line, self.expected
+            );
+        }
+        if self.text.is_empty() {
+            return write!(
+                f,
+                "{}:{}: expected {}, found an empty line",
+                file, self.line, self.expected
+            );
+        }
+
+        writeln!(
+            f,
+            "{}:{}:{}: expected {}",
+            file, self.line, self.column, self.expected
+        )?;
+        writeln!(f, "    {}", self.text)?;
+        write!(f, "    {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Why an input file couldn't be turned into a puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    Io(String, std::io::Error),
+    Parse(ParseError),
+    /// The parser panicked on the named input.
+    Panicked(String, Panic),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io(file, err) => write!(f, "Could not read input file {}: {}", file, err),
+            InputError::Parse(err) => write!(f, "Could not parse {}", err),
+            InputError::Panicked(file, panic) => write!(f, "Parsing {} {}", file, panic),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(_, err) => Some(err),
+            InputError::Parse(err) => Some(err),
+            InputError::Panicked(..) => None,
+        }
+    }
+}
+
+/// Puzzle input text, along with the name errors should refer to it by.
+#[derive(Debug, Clone)]
+pub struct RawInput {
+    pub name: String,
+    pub text: String,
+}
+
+impl RawInput {
+    pub fn from_file(path: &str) -> Result<Self, InputError> {
+        let text =
+            std::fs::read_to_string(path).map_err(|err| InputError::Io(path.to_string(), err))?;
+        Ok(RawInput {
+            name: path.to_string(),
+            text,
+        })
+    }
+
+    pub fn from_stdin() -> Result<Self, InputError> {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|err| InputError::Io("<stdin>".to_string(), err))?;
+        Ok(RawInput {
+            name: "<stdin>".to_string(),
+            text,
+        })
+    }
+}
+
+/// Reads every line of the input, failing on the first one that can't be read.
+pub fn read_lines(reader: impl BufRead) -> Result<Vec<String>, ParseError> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.map_err(|err| {
+                ParseError::new(&err.to_string(), "a line of UTF-8 text").on_line(i + 1)
+            })
+        })
+        .collect()
+}
+
+/// Parses each line with `parse`, attaching the line number to any error.
+pub fn parse_lines<T, F>(reader: impl BufRead, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    parse_each(&read_lines(reader)?, 1, parse)
+}
+
+/// Like `parse_lines`, for lines that were already read. `first_line` is the
+/// line number of `lines[0]` within the file.
+pub fn parse_each<T, F>(
+    lines: &[String],
+    first_line: usize,
+    mut parse: F,
+) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.on_line(first_line + i)))
+        .collect()
+}
+
+/// Parses `token` (a slice of `line`) with its `FromStr` impl.
+pub fn parse_token<T: FromStr>(
+    line: &str,
+    token: &str,
+    expected: impl Into<String>,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line, token, expected))
+}
+
+/// Reads a rectangular grid of single digits, one row per line.
+pub fn parse_digit_grid<T: From<u8> + Clone>(
+    reader: impl BufRead,
+) -> Result<Array2<T>, ParseError> {
+    let lines = read_lines(reader)?;
+    let width = lines.first().map_or(0, |line| line.chars().count());
+
+    let rows = parse_each(&lines, 1, |line| {
+        if line.chars().count() != width {
+            // Point at the first character past the expected width, or at the
+            // end of a row that's too short.
+            let end = line
+                .char_indices()
+                .nth(width)
+                .map_or(line.len(), |(i, _)| i);
+            return Err(ParseError::at(
+                line,
+                &line[end..],
+                format!("a row of {} digits", width),
+            ));
+        }
+        line.char_indices()
+            .map(|(i, c)| match c.to_digit(10) {
+                Some(digit) => Ok(T::from(digit as u8)),
+                None => Err(ParseError::at(line, &line[i..], "a digit")),
+            })
+            .collect::<Result<Vec<T>, _>>()
+    })?;
+
+    Ok(Array2::from_shape_fn((rows.len(), width), |(r, c)| {
+        rows[r][c].clone()
+    }))
+}
@@ -0,0 +1,187 @@
+//! Allocation accounting. The binary installs [`CountingAllocator`] as its
+//! global allocator, but it only counts once [`enable`] has been called, so
+//! runs that don't ask for memory figures pay for no more than a flag check.
+//! Processes without it, like tests that don't install it, never count.
+//!
+//! Counts are kept per thread, which is where every part runs, so parts solved
+//! at the same time don't see each other's allocations.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The system allocator, counting what it hands out once enabled.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Set by the first allocation `CountingAllocator` makes, which tells that
+/// it's the global allocator.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Counts {
+    allocations: u64,
+    allocated: u64,
+    /// Can go negative on a thread that frees what another one allocated.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // A const initializer and no destructor, so the allocator can use it
+    // without allocating itself.
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocations: 0,
+            allocated: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn update(f: impl FnOnce(&mut Counts)) {
+    // Threads being torn down have no counters left; their frees go uncounted.
+    let _ = COUNTS.try_with(|counts| {
+        let mut current = counts.get();
+        f(&mut current);
+        current.peak = current.peak.max(current.live);
+        counts.set(current);
+    });
+}
+
+fn allocated(size: usize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    if ENABLED.load(Ordering::Relaxed) {
+        update(|counts| {
+            counts.allocations += 1;
+            counts.allocated += size as u64;
+            counts.live += size as i64;
+        });
+    }
+}
+
+fn freed(size: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        update(|counts| counts.live -= size as i64);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    /// Counted as a new allocation of `new_size` bytes that frees the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting allocations, for the rest of the process. Does nothing
+/// unless `CountingAllocator` is the global allocator.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether allocations are being counted: `enable` was called and
+/// `CountingAllocator` is the global allocator.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed) && INSTALLED.load(Ordering::Relaxed)
+}
+
+/// What a piece of code allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    pub allocations: u64,
+    /// Every byte allocated, freed or not.
+    pub bytes: u64,
+    /// The most it held at once on top of what was live when it started.
+    pub peak: u64,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocation{}, {} allocated, {} peak",
+            self.allocations,
+            if self.allocations == 1 { "" } else { "s" },
+            get_size(self.bytes),
+            get_size(self.peak)
+        )
+    }
+}
+
+/// Runs `f` on this thread and returns what it allocated, or `None` when
+/// allocations aren't being counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    let before = COUNTS.with(Cell::get);
+    // Track the peak from here, and put back the outer one afterwards so
+    // measuring inside another measurement doesn't hide its peak.
+    COUNTS.with(|counts| {
+        counts.set(Counts {
+            peak: before.live,
+            ..before
+        })
+    });
+    let result = f();
+    let after = COUNTS.with(Cell::get);
+    COUNTS.with(|counts| {
+        counts.set(Counts {
+            peak: after.peak.max(before.peak),
+            ..after
+        })
+    });
+
+    let usage = Usage {
+        allocations: after.allocations - before.allocations,
+        bytes: after.allocated - before.allocated,
+        peak: (after.peak - before.live).max(0) as u64,
+    };
+    (result, Some(usage))
+}
+
+pub fn get_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
@@ -0,0 +1,88 @@
+use super::bench::{BenchOptions, BenchReport};
+use super::solver::{Puzzle, Report, SolveOptions};
+use super::{InputError, RawInput};
+use crate::config;
+use std::error::Error;
+use std::fmt::Display;
+
+/// Inputs are kept per event, in `inputs/YYYY/NN` unless the config puts
+/// them elsewhere.
+pub fn input_file(year: isize, number: isize) -> String {
+    format!("{}/{}/{:02}", config::inputs_dir().display(), year, number)
+}
+
+/// A day's solver as listed in the registry that `build.rs` generates.
+pub struct Day {
+    pub year: isize,
+    pub number: isize,
+    pub title: &'static str,
+    puzzle: &'static (dyn Puzzle + Sync),
+}
+
+impl Day {
+    pub(super) const fn new(
+        year: isize,
+        number: isize,
+        title: &'static str,
+        puzzle: &'static (dyn Puzzle + Sync),
+    ) -> Self {
+        Day {
+            year,
+            number,
+            title,
+            puzzle,
+        }
+    }
+
+    pub fn input_file(&self) -> String {
+        input_file(self.year, self.number)
+    }
+
+    pub fn solve(&self, input: &RawInput, parts: isize) -> Result<Report, InputError> {
+        self.puzzle.solve(input, &SolveOptions::parts(parts))
+    }
+
+    pub fn solve_with(
+        &self,
+        input: &RawInput,
+        options: &SolveOptions,
+    ) -> Result<Report, InputError> {
+        self.puzzle.solve(input, options)
+    }
+
+    pub fn bench(
+        &self,
+        input: &RawInput,
+        options: &BenchOptions,
+    ) -> Result<BenchReport, InputError> {
+        self.puzzle.bench(input, options)
+    }
+}
+
+/// Asked for a day that has no solver. `available` lists the days of that
+/// year that do.
+#[derive(Debug)]
+pub struct UnknownDay {
+    pub year: isize,
+    pub number: isize,
+    pub available: Vec<isize>,
+}
+
+impl Display for UnknownDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.available.is_empty() {
+            return write!(f, "There are no solutions for {} yet", self.year);
+        }
+
+        let available: Vec<String> = self.available.iter().map(|n| n.to_string()).collect();
+        write!(
+            f,
+            "Day {} of {} is not implemented. Available days: {}",
+            self.number,
+            self.year,
+            available.join(", ")
+        )
+    }
+}
+
+impl Error for UnknownDay {}
@@ -1,48 +1,396 @@
+use super::bench::{self, BenchOptions, BenchReport};
+use super::cancel;
+use super::memory::{self, Usage};
+use super::panics::{self, Panic};
+use super::{InputError, ParseError, RawInput};
+use std::error::Error;
 use std::fmt::Display;
-use std::fs::File;
-use std::io::BufReader;
-use std::time::Instant;
-
-macro_rules! printResult {
-    ($part:expr, $result:expr, $start:expr ) => {
-        match $result {
-            Ok(res) => println!(
-                "Solution to part {}: {} ({})",
-                $part,
-                res,
-                get_elapsed($start)
-            ),
-            Err(val) => println!("Solution to part {} errored: {}", $part, val),
+use std::io::BufRead;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The outcome of solving one part of a puzzle.
+pub type Answer<T> = Result<T, SolverError>;
+
+/// Why a part has no answer.
+#[derive(Debug, Clone)]
+pub enum SolverError {
+    /// The part hasn't been written, or there's nothing to write.
+    NotImplemented,
+    /// The search ran out without finding the thing described.
+    NoSolution(String),
+    /// The input parsed, but breaks an assumption the solver relies on.
+    InvalidInput {
+        reason: String,
+        source: Option<Arc<dyn Error + Send + Sync>>,
+    },
+    /// The answer or a value on the way to it doesn't fit its type.
+    Overflow {
+        what: String,
+        source: Option<Arc<dyn Error + Send + Sync>>,
+    },
+    /// The part ran for longer than it was allowed to.
+    Timeout(Duration),
+    Panicked(Panic),
+}
+
+impl SolverError {
+    pub fn invalid_input(reason: impl Into<String>) -> Self {
+        SolverError::InvalidInput {
+            reason: reason.into(),
+            source: None,
+        }
+    }
+
+    pub fn overflow(what: impl Into<String>) -> Self {
+        SolverError::Overflow {
+            what: what.into(),
+            source: None,
+        }
+    }
+
+    /// Records `err` as the cause of an invalid input or overflow.
+    pub fn caused_by(mut self, err: impl Error + Send + Sync + 'static) -> Self {
+        if let SolverError::InvalidInput { source, .. } | SolverError::Overflow { source, .. } =
+            &mut self
+        {
+            *source = Some(Arc::new(err));
+        }
+        self
+    }
+
+    /// A short name for the kind of error, for machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SolverError::NotImplemented => "not-implemented",
+            SolverError::NoSolution(_) => "no-solution",
+            SolverError::InvalidInput { .. } => "invalid-input",
+            SolverError::Overflow { .. } => "overflow",
+            SolverError::Timeout(_) => "timeout",
+            SolverError::Panicked(_) => "panic",
+        }
+    }
+
+    /// The process exit code for a run that failed with this error.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            SolverError::NotImplemented => 3,
+            SolverError::NoSolution(_) => 4,
+            SolverError::InvalidInput { .. } => 5,
+            SolverError::Overflow { .. } => 6,
+            SolverError::Timeout(_) => 7,
+            SolverError::Panicked(_) => 8,
+        }
+    }
+}
+
+impl Display for SolverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolverError::NotImplemented => write!(f, "not implemented"),
+            SolverError::NoSolution(what) => write!(f, "no solution: {}", what),
+            SolverError::InvalidInput { reason, .. } => write!(f, "invalid input: {}", reason),
+            SolverError::Overflow { what, .. } => write!(f, "overflow: {}", what),
+            SolverError::Timeout(limit) => write!(f, "timed out after {}", get_elapsed(*limit)),
+            SolverError::Panicked(panic) => write!(f, "{}", panic),
         }
+    }
+}
+
+impl Error for SolverError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolverError::InvalidInput {
+                source: Some(source),
+                ..
+            }
+            | SolverError::Overflow {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::num::TryFromIntError> for SolverError {
+    fn from(err: std::num::TryFromIntError) -> Self {
+        SolverError::overflow("integer conversion").caused_by(err)
+    }
+}
+
+/// `err` followed by its chain of causes, separated by colons.
+pub fn error_chain(err: &dyn Error) -> String {
+    let mut message = err.to_string();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        message.push_str(&format!(": {}", cause));
+        source = cause.source();
+    }
+    message
+}
+
+/// The reason or description inside an invalid input or overflow error,
+/// followed by its causes.
+fn cause_chain(err: &SolverError) -> String {
+    let mut message = match err {
+        SolverError::InvalidInput { reason, .. } => reason.clone(),
+        SolverError::Overflow { what, .. } => what.clone(),
+        _ => return error_chain(err),
     };
+    if let Some(source) = err.source() {
+        message.push_str(&format!(": {}", error_chain(source)));
+    }
+    message
 }
 
+/// The outcomes of both parts of a puzzle, in order.
+pub type Answers<S> = (
+    Answer<<S as Solver>::Output1>,
+    Answer<<S as Solver>::Output2>,
+);
+
 pub trait Solver {
     type Input;
     type Output1: Display;
     type Output2: Display;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input;
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String>;
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String>;
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError>;
+    fn solve_first(&self, input: &Self::Input) -> Answer<Self::Output1>;
+    fn solve_second(&self, input: &Self::Input) -> Answer<Self::Output2>;
 
-    fn solve(&self, filename: String, parts: isize) {
-        let file = File::open(filename).expect("input file not found");
-        let input = self.read_input(BufReader::new(&file));
+    /// Parses input held in memory, such as an example from the puzzle text.
+    fn read_str(&self, input: &str) -> Result<Self::Input, ParseError> {
+        self.read_input(input.as_bytes())
+    }
+
+    /// Parses `input` and solves both parts, returning the answers instead of printing them.
+    fn solve_str(&self, input: &str) -> Result<Answers<Self>, ParseError> {
+        let input = self.read_str(input)?;
+        Ok((self.solve_first(&input), self.solve_second(&input)))
+    }
+
+    /// Parses `raw`, reporting errors and panics against its name.
+    fn parse(&self, raw: &RawInput) -> Result<Self::Input, InputError> {
+        panics::catch(|| self.read_str(&raw.text))
+            .map_err(|panic| InputError::Panicked(raw.name.clone(), panic))?
+            .map_err(|err| InputError::Parse(err.in_file(&raw.name)))
+    }
+
+    /// Parses `input` and solves the selected `parts`. A panic while parsing
+    /// or in a part is caught and reported, and the other part still runs.
+    fn solve(&self, input: &RawInput, parts: isize) -> Result<Report, InputError> {
+        let start = Instant::now();
+        let (input, parse_memory) = memory::measure(|| self.parse(input));
+        let input = input?;
+
+        let mut report = Report {
+            parse_elapsed: start.elapsed(),
+            parse_memory,
+            parts: Vec::new(),
+        };
         if parts & 0x1 > 0 {
-            let start = Instant::now();
-            printResult!(1, self.solve_first(&input), start);
+            report.parts.push(run_part(1, || self.solve_first(&input)));
         }
         if parts & 0x2 > 0 {
-            let start = Instant::now();
-            printResult!(2, self.solve_second(&input), start)
+            report.parts.push(run_part(2, || self.solve_second(&input)));
+        }
+        Ok(report)
+    }
+}
+
+/// Object-safe entry points into a `Solver`, so days with different input and
+/// output types can be picked by their number.
+pub trait Puzzle {
+    fn solve(&'static self, input: &RawInput, options: &SolveOptions)
+        -> Result<Report, InputError>;
+    fn bench(&self, input: &RawInput, options: &BenchOptions) -> Result<BenchReport, InputError>;
+}
+
+impl<S> Puzzle for S
+where
+    S: Solver + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn solve(
+        &'static self,
+        input: &RawInput,
+        options: &SolveOptions,
+    ) -> Result<Report, InputError> {
+        match (options.timeout, options.parallel) {
+            (Some(limit), parallel) => {
+                cancel::solve_within(self, input, options.parts, limit, parallel)
+            }
+            (None, true) => solve_in_parallel(self, input, options.parts),
+            (None, false) => Solver::solve(self, input, options.parts),
+        }
+    }
+
+    fn bench(&self, input: &RawInput, options: &BenchOptions) -> Result<BenchReport, InputError> {
+        bench::bench(self, input, options)
+    }
+}
+
+/// Which parts to solve, how long each may take and whether they may run at
+/// the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolveOptions {
+    pub parts: isize,
+    /// Without a limit, parts run on the calling thread for as long as they take.
+    pub timeout: Option<Duration>,
+    /// Solves both parts at once, on the same parsed input.
+    pub parallel: bool,
+}
+
+impl SolveOptions {
+    pub fn parts(parts: isize) -> Self {
+        SolveOptions {
+            parts,
+            timeout: None,
+            parallel: false,
         }
     }
 }
 
-fn get_elapsed(start: Instant) -> String {
-    let elapsed = start.elapsed();
+/// Parses a time limit such as `30s`, `500ms`, `2m` or `1.5`, which is taken
+/// to be in seconds.
+pub fn parse_timeout(text: &str) -> Result<Duration, String> {
+    let text = text.trim();
+    let (number, unit) = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .map_or((text, "s"), |i| text.split_at(i));
+    let scale = match unit {
+        "ms" => 0.001,
+        "s" => 1.0,
+        "m" => 60.0,
+        _ => {
+            return Err(format!(
+                "Invalid timeout '{}': unknown unit '{}'",
+                text, unit
+            ))
+        }
+    };
+    match number.parse::<f64>() {
+        Ok(number) if number > 0.0 => Duration::try_from_secs_f64(number * scale)
+            .map_err(|err| format!("Invalid timeout '{}': {}", text, err)),
+        _ => Err(format!("Invalid timeout '{}'", text)),
+    }
+}
+
+/// How one part of a puzzle went: its rendered answer or error, and how long it took.
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<String, SolverError>,
+    pub elapsed: Duration,
+    /// What the part allocated, when allocations are being counted.
+    pub memory: Option<Usage>,
+}
+
+/// The results of every part that `Solver::solve` was asked to run.
+#[derive(Debug, Clone)]
+pub struct Report {
+    /// How long parsing the input took.
+    pub parse_elapsed: Duration,
+    /// What parsing allocated, when allocations are being counted.
+    pub parse_memory: Option<Usage>,
+    pub parts: Vec<PartReport>,
+}
+
+pub(super) fn run_part<T: Display>(part: u8, solve: impl FnOnce() -> Answer<T>) -> PartReport {
+    let start = Instant::now();
+    let (answer, memory) = memory::measure(|| {
+        panics::catch(|| solve().map(|res| res.to_string()))
+            .unwrap_or_else(|panic| Err(SolverError::Panicked(panic)))
+    });
+    PartReport {
+        part,
+        answer,
+        elapsed: start.elapsed(),
+        memory,
+    }
+}
+
+/// Like `Solver::solve`, but with part 1 on a thread of its own while part 2
+/// runs on this one.
+fn solve_in_parallel<S>(solver: &S, raw: &RawInput, parts: isize) -> Result<Report, InputError>
+where
+    S: Solver + Sync,
+    S::Input: Sync,
+{
+    let start = Instant::now();
+    let (input, parse_memory) = memory::measure(|| solver.parse(raw));
+    let input = input?;
+    let parse_elapsed = start.elapsed();
+
+    let (first, second) = thread::scope(|scope| {
+        let first =
+            (parts & 0x1 > 0).then(|| scope.spawn(|| run_part(1, || solver.solve_first(&input))));
+        let second = (parts & 0x2 > 0).then(|| run_part(2, || solver.solve_second(&input)));
+        let first = first.map(|first| first.join().expect("parts catch their own panics"));
+        (first, second)
+    });
+    Ok(Report {
+        parse_elapsed,
+        parse_memory,
+        parts: first.into_iter().chain(second).collect(),
+    })
+}
+
+impl Display for PartReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.answer {
+            Ok(res) => {
+                write!(
+                    f,
+                    "Solution to part {}: {} ({}",
+                    self.part,
+                    res,
+                    get_elapsed(self.elapsed)
+                )?;
+                if let Some(memory) = &self.memory {
+                    write!(f, ", {}", memory)?;
+                }
+                write!(f, ")")
+            }
+            Err(err) => {
+                write!(f, "Part {} ", self.part)?;
+                match err {
+                    SolverError::NotImplemented => write!(f, "is not implemented"),
+                    SolverError::NoSolution(what) => write!(
+                        f,
+                        "found no solution: {} ({})",
+                        what,
+                        get_elapsed(self.elapsed)
+                    ),
+                    SolverError::InvalidInput { .. } => {
+                        write!(f, "rejected the input: {}", cause_chain(err))
+                    }
+                    SolverError::Overflow { .. } => write!(f, "overflowed: {}", cause_chain(err)),
+                    SolverError::Timeout(limit) => {
+                        write!(f, "timed out after {}", get_elapsed(*limit))
+                    }
+                    SolverError::Panicked(panic) => write!(f, "{}", panic),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(memory) = &self.parse_memory {
+            writeln!(f, "Parsing: {}", memory)?;
+        }
+        for part in &self.parts {
+            writeln!(f, "{}", part)?;
+        }
+        Ok(())
+    }
+}
 
+pub fn get_elapsed(elapsed: Duration) -> String {
     let nanos = elapsed.as_nanos();
     let decimals = format!("{}", nanos).len();
     match decimals {
@@ -1,6 +1,8 @@
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::input::{parse_lines, parse_token};
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Sonar Sweep";
 
 pub struct Problem;
 
@@ -9,16 +11,13 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .filter_map(|x| x.ok())
-            .map(|line| line.parse())
-            .filter_map(|x| x.ok())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| {
+            parse_token(line, line, "a depth measurement")
+        })
     }
 
-    fn solve_first(&self, input: &Vec<isize>) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Vec<isize>) -> Result<Self::Output1, SolverError> {
         let result = input
             .into_iter()
             .map(|v| (0, v))
@@ -31,14 +30,14 @@ impl Solver for Problem {
                     }
                 },
             )
-            .unwrap();
+            .ok_or_else(|| SolverError::invalid_input("there are no depth measurements"))?;
 
         let (total, _) = result;
 
         Ok(total)
     }
 
-    fn solve_second(&self, input: &Vec<isize>) -> Result<isize, String> {
+    fn solve_second(&self, input: &Vec<isize>) -> Result<isize, SolverError> {
         let sums = input
             .into_iter()
             .scan((0, 0, 0), |state, v| {
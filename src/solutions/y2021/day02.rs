@@ -0,0 +1,49 @@
+use crate::solutions::input::{parse_lines, parse_token};
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Dive!";
+
+pub struct Problem;
+
+impl Solver for Problem {
+    type Input = Vec<(isize, isize)>;
+    type Output1 = isize;
+    type Output2 = isize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| {
+            let (direction, quantity) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(line, "'<direction> <quantity>'"))?;
+            let quantity: isize = parse_token(line, quantity, "an integer quantity")?;
+
+            match direction {
+                "forward" => Ok((quantity, 0)),
+                "down" => Ok((0, quantity)),
+                "up" => Ok((0, -quantity)),
+                _ => Err(ParseError::at(
+                    line,
+                    direction,
+                    "one of 'forward', 'down' or 'up'",
+                )),
+            }
+        })
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
+        let (horizontal, depth) = input
+            .into_iter()
+            .fold((0, 0), |(h0, d0), (h, d)| (h0 + h, d0 + d));
+
+        Ok(horizontal * depth)
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<isize, SolverError> {
+        let (horizontal, depth, _) = input.into_iter().fold((0, 0, 0), |(h0, d0, a0), (h, a)| {
+            (h0 + h, d0 + (a0 * h), a0 + a)
+        });
+
+        Ok(horizontal * depth)
+    }
+}
@@ -1,10 +1,11 @@
-use super::Solver;
+use crate::solutions::input::{parse_each, read_lines};
+use crate::solutions::{ParseError, Solver, SolverError};
 use itertools::partition;
-use std::convert::TryInto;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::vec::Vec;
 
+pub const TITLE: &str = "Binary Diagnostic";
+
 pub struct Problem;
 
 impl Solver for Problem {
@@ -12,22 +13,21 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let mut lines = file_reader.lines().peekable();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
 
-        let length = lines.peek().unwrap().as_ref().unwrap().len();
+        let length = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input("a binary number").on_line(1))?
+            .len();
+        let numbers = parse_each(&lines, 1, |line| {
+            usize::from_str_radix(line, 2).map_err(|_| ParseError::new(line, "a binary number"))
+        })?;
 
-        (
-            length,
-            lines
-                .filter_map(|x| x.ok())
-                .map(|line| usize::from_str_radix(line.as_str(), 2))
-                .filter_map(|x| x.ok())
-                .collect(),
-        )
+        Ok((length, numbers))
     }
 
-    fn solve_first(&self, (bits, input): &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, (bits, input): &Self::Input) -> Result<Self::Output1, SolverError> {
         let mut vec = vec![0; *bits];
 
         for n in input {
@@ -53,11 +53,16 @@ impl Solver for Problem {
         Ok(epsilon * gamma)
     }
 
-    fn solve_second(&self, (bits, input): &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, (bits, input): &Self::Input) -> Result<Self::Output2, SolverError> {
+        if *bits < 2 {
+            return Err(SolverError::invalid_input(
+                "the numbers need at least 2 bits",
+            ));
+        }
         let mut clone = input.clone();
 
         let main_split = partition(&mut clone.iter_mut(), |x| x & (0x01 << (bits - 1)) >= 1);
-        let next_bits = (bits - 2).try_into().unwrap();
+        let next_bits = isize::try_from(*bits)? - 2;
 
         let (oxygen, co2) = if main_split > input.len() / 2 {
             (
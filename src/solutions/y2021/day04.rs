@@ -0,0 +1,219 @@
+use crate::solutions::input::{parse_token, read_lines};
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Giant Squid";
+
+pub struct Problem;
+
+#[derive(Debug)]
+pub struct PInput {
+    pub sequence: Vec<usize>,
+    pub bingo_cards: Vec<Vec<Vec<usize>>>,
+}
+
+pub struct BingoCard {
+    card: Vec<Vec<usize>>,
+    marks: Vec<Vec<bool>>,
+    has_won: bool,
+}
+
+impl BingoCard {
+    pub fn new(card: &Vec<Vec<usize>>) -> Self {
+        let marks = card
+            .into_iter()
+            .map(|line| line.into_iter().map(|_| false).collect())
+            .collect();
+
+        BingoCard {
+            card: card.clone(),
+            marks,
+            has_won: false,
+        }
+    }
+
+    pub fn mark_num(&mut self, num: usize) -> bool {
+        if self.has_won {
+            return false;
+        }
+
+        for i in 0..self.card.len() {
+            for j in 0..self.card[i].len() {
+                self.marks[i][j] = self.marks[i][j] || (self.card[i][j] == num)
+            }
+        }
+
+        self.has_won = self.has_won || self.check_win();
+        self.has_won
+    }
+
+    pub fn get_value(&self) -> usize {
+        let mut total = 0;
+
+        for i in 0..self.card.len() {
+            for j in 0..self.card[i].len() {
+                if !self.marks[i][j] {
+                    total = total + self.card[i][j];
+                }
+            }
+        }
+
+        total
+    }
+
+    fn check_win(&self) -> bool {
+        // Check rows
+        for i in 0..self.marks.len() {
+            let line = &(self.marks[i]);
+            if line.into_iter().all(|x| *x) {
+                return true;
+            }
+        }
+
+        // Check columns
+        for j in 0..self.marks[0].len() {
+            let mut win = true;
+            for i in 0..self.marks.len() {
+                if !self.marks[i][j] {
+                    win = false;
+                    break;
+                }
+            }
+            if win {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+const BOARD_SIZE: usize = 5;
+
+/// Parses one board from its non-blank `rows`, which must form a full 5×5 grid.
+fn read_card(lines: &[String], rows: &[(usize, &String)]) -> Result<Vec<Vec<usize>>, ParseError> {
+    let card = rows
+        .iter()
+        .map(|(n, line)| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() != BOARD_SIZE {
+                // Point at the first extra number, or at the end of a short row.
+                let at = tokens
+                    .get(BOARD_SIZE)
+                    .copied()
+                    .unwrap_or(&line[line.len()..]);
+                return Err(ParseError::at(
+                    line,
+                    at,
+                    format!("a row of {} card numbers", BOARD_SIZE),
+                )
+                .on_line(*n));
+            }
+            tokens
+                .into_iter()
+                .map(|x| parse_token(line, x, "a card number"))
+                .collect::<Result<_, _>>()
+                .map_err(|err| err.on_line(*n))
+        })
+        .collect::<Result<Vec<Vec<usize>>, _>>()?;
+
+    if rows.len() > BOARD_SIZE {
+        let (n, line) = rows[BOARD_SIZE];
+        return Err(
+            ParseError::new(line, format!("a blank line after {} rows", BOARD_SIZE)).on_line(n),
+        );
+    }
+    if rows.len() < BOARD_SIZE {
+        let next = rows.last().map_or(0, |(n, _)| *n) + 1;
+        let expected = format!("a board of {} rows", BOARD_SIZE);
+        return Err(match lines.get(next - 1) {
+            Some(line) => ParseError::new(line, expected).on_line(next),
+            None => ParseError::end_of_input(expected).on_line(next),
+        });
+    }
+
+    Ok(card)
+}
+
+impl Solver for Problem {
+    type Input = PInput;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+
+        let first = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input("the drawn numbers").on_line(1))?;
+        let sequence: Vec<usize> = first
+            .split(",")
+            .map(|x| parse_token(first, x, "a drawn number"))
+            .collect::<Result<_, _>>()
+            .map_err(|err| err.on_line(1))?;
+
+        // Boards are blocks of lines separated by blank ones. Keep each row's
+        // 1-based line number for error messages.
+        let mut blocks: Vec<Vec<(usize, &String)>> = vec![Vec::new()];
+        for (i, line) in lines.iter().enumerate().skip(1) {
+            if line.trim().is_empty() {
+                blocks.push(Vec::new());
+            } else {
+                blocks.last_mut().unwrap().push((i + 1, line));
+            }
+        }
+        blocks.retain(|block| !block.is_empty());
+
+        if blocks.is_empty() {
+            return Err(ParseError::end_of_input("a bingo board").on_line(lines.len() + 1));
+        }
+
+        let bingo_cards: Vec<Vec<Vec<usize>>> = blocks
+            .into_iter()
+            .map(|block| read_card(&lines, &block))
+            .collect::<Result<_, _>>()?;
+
+        Ok(PInput {
+            sequence,
+            bingo_cards,
+        })
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
+        let mut bingo_cards: Vec<_> = (&input.bingo_cards)
+            .into_iter()
+            .map(|card| BingoCard::new(&card))
+            .collect();
+
+        for i in 0..input.sequence.len() {
+            let value = input.sequence[i];
+            for c in 0..bingo_cards.len() {
+                if bingo_cards[c].mark_num(value) {
+                    // println!("{} {} {}", i, c, value);
+                    return Ok(bingo_cards[c].get_value() * value);
+                }
+            }
+        }
+
+        Err(SolverError::NoSolution("no board wins".to_string()))
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
+        let mut bingo_cards: Vec<_> = (&input.bingo_cards)
+            .into_iter()
+            .map(|card| BingoCard::new(&card))
+            .collect();
+
+        let mut last_win = 0;
+        for i in 0..input.sequence.len() {
+            let value = input.sequence[i];
+            for c in 0..bingo_cards.len() {
+                if bingo_cards[c].mark_num(value) {
+                    last_win = bingo_cards[c].get_value() * value;
+                }
+            }
+        }
+
+        Ok(last_win)
+    }
+}
@@ -1,9 +1,11 @@
-use super::Solver;
+use crate::solutions::input::{parse_lines, parse_token};
+use crate::solutions::{ParseError, Solver, SolverError};
 use regex::Regex;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::str::FromStr;
 
+pub const TITLE: &str = "Hydrothermal Venture";
+
 pub struct Problem;
 
 impl Solver for Problem {
@@ -11,16 +13,11 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.parse())
-            .map(|x| x.unwrap())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, str::parse)
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let max = get_max(input);
         let mut field = ndarray::Array2::<usize>::zeros((max.x + 1, max.y + 1));
 
@@ -39,7 +36,7 @@ impl Solver for Problem {
         Ok(result)
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         let max = get_max(input);
         let mut field = ndarray::Array2::<usize>::zeros((max.y + 1, max.x + 1));
 
@@ -76,23 +73,27 @@ pub struct Line {
 }
 
 impl FromStr for Line {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         lazy_static::lazy_static! {
-            static ref LINE_RGX: Regex = Regex::new(r"(\d+),(\d+) -> (\d+),(\d+)").unwrap();
+            static ref LINE_RGX: Regex = Regex::new(r"^(\d+),(\d+) -> (\d+),(\d+)$").unwrap();
         }
 
+        let coordinate = |captures: &regex::Captures, i| {
+            parse_token(s, captures.get(i).unwrap().as_str(), "a coordinate")
+        };
+
         match LINE_RGX.captures(s) {
-            None => Err(format!("Error parsing line '{}'", s)),
+            None => Err(ParseError::new(s, "'<x>,<y> -> <x>,<y>'")),
             Some(captures) => Ok(Line {
                 start: Point {
-                    x: captures[1].parse().unwrap(),
-                    y: captures[2].parse().unwrap(),
+                    x: coordinate(&captures, 1)?,
+                    y: coordinate(&captures, 2)?,
                 },
                 end: Point {
-                    x: captures[3].parse().unwrap(),
-                    y: captures[4].parse().unwrap(),
+                    x: coordinate(&captures, 3)?,
+                    y: coordinate(&captures, 4)?,
                 },
             }),
         }
@@ -1,27 +1,38 @@
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::input::{parse_token, read_lines};
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Lanternfish";
 
 pub struct Problem;
 
+/// A newborn fish's timer; nothing in the input may be higher.
+const MAX_TIMER: usize = 8;
+const EXPECTED: &str = "a lanternfish timer from 0 to 8";
+
 impl Solver for Problem {
     type Input = Vec<usize>;
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .filter_map(|x| x.ok())
-            .next()
-            .unwrap()
-            .split(",")
-            .map(|line| line.parse())
-            .filter_map(|x| x.ok())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+        let line = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input("a list of timers").on_line(1))?;
+
+        line.split(",")
+            .map(|timer| {
+                parse_token(line, timer, EXPECTED).and_then(|value: usize| match value {
+                    0..=MAX_TIMER => Ok(value),
+                    _ => Err(ParseError::at(line, timer, EXPECTED)),
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|err| err.on_line(1))
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let mut fish = input.clone();
 
         for _ in 0..80 {
@@ -44,8 +55,8 @@ impl Solver for Problem {
         Ok(fish.len())
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
-        const N: usize = 9;
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
+        const N: usize = MAX_TIMER + 1;
         let mut days = vec![0; N];
         input.into_iter().fold(&mut days, |acc, fish| {
             acc[*fish] += 1;
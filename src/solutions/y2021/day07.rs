@@ -1,6 +1,8 @@
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::input::{parse_token, read_lines};
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "The Treachery of Whales";
 
 pub struct Problem;
 
@@ -9,19 +11,19 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .filter_map(|x| x.ok())
-            .next()
-            .unwrap()
-            .split(",")
-            .map(|line| line.parse())
-            .filter_map(|x| x.ok())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+        let line = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input("a list of positions").on_line(1))?;
+
+        line.split(",")
+            .map(|position| parse_token(line, position, "a crab position"))
+            .collect::<Result<_, _>>()
+            .map_err(|err| err.on_line(1))
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         fn calc_fuel(a: usize, b: usize) -> usize {
             (a as isize - b as isize).abs() as usize
         }
@@ -34,7 +36,7 @@ impl Solver for Problem {
         Ok(fuel_cost.fold(usize::MAX, |acc, x| acc.min(x)))
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         fn calc_fuel(a: usize, b: usize) -> usize {
             let d = (a as isize - b as isize).abs() as usize;
             (d * (d + 1)) / 2
@@ -1,12 +1,14 @@
 use itertools::Itertools;
 
-use super::Solver;
+use crate::solutions::input::parse_lines;
+use crate::solutions::{ParseError, Solver, SolverError};
 use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::ops::Sub;
 
+pub const TITLE: &str = "Seven Segment Search";
+
 pub struct Problem;
 
 impl Solver for Problem {
@@ -14,21 +16,24 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| {
-                let (all_symbols, output) = line.split(" | ").collect_tuple().unwrap();
-
-                let p_all_symbols: [String; 10] = parse_segments(all_symbols).try_into().unwrap();
-                let p_output: [String; 4] = parse_segments(output).try_into().unwrap();
-                (p_all_symbols, p_output)
-            })
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| {
+            let (all_symbols, output) = line
+                .split(" | ")
+                .collect_tuple()
+                .ok_or_else(|| ParseError::new(line, "'<10 patterns> | <4 digits>'"))?;
+
+            let p_all_symbols: [String; 10] = parse_segments(all_symbols)
+                .try_into()
+                .map_err(|_| ParseError::at(line, all_symbols, "10 signal patterns"))?;
+            let p_output: [String; 4] = parse_segments(output)
+                .try_into()
+                .map_err(|_| ParseError::at(line, output, "4 output digits"))?;
+            Ok((p_all_symbols, p_output))
+        })
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let result = input
             .into_iter()
             .map(|(_, x)| {
@@ -42,7 +47,7 @@ impl Solver for Problem {
         Ok(result)
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         Ok(input.iter().map(|l| decode_line(l)).sum())
     }
 }
@@ -1,10 +1,12 @@
 use itertools::Itertools;
 use ndarray::Array2;
 
-use super::Solver;
+use crate::solutions::input::parse_digit_grid;
+use crate::solutions::{ParseError, Solver, SolverError};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Smoke Basin";
 
 pub struct Problem;
 
@@ -13,17 +15,11 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines: Vec<Vec<u8>> = file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.bytes().map(|c| c - b'0').collect::<Vec<u8>>())
-            .collect();
-
-        ndarray::Array2::from_shape_fn((lines.len(), lines[0].len()), |(r, c)| lines[r][c])
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_digit_grid(file_reader)
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let result = get_minima(input)
             .into_iter()
             .map(|(_, v)| 1 + (v as usize))
@@ -32,8 +28,14 @@ impl Solver for Problem {
         Ok(result)
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
-        let result = get_minima(input)
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
+        let minima = get_minima(input);
+        if minima.len() < 3 {
+            return Err(SolverError::NoSolution(
+                "there are fewer than 3 basins".to_string(),
+            ));
+        }
+        let result = minima
             .into_iter()
             .map(|(p, _)| {
                 let r = get_basin(input, p);
@@ -97,19 +99,12 @@ fn get_minima(input: &Array2<u8>) -> Vec<((usize, usize), u8)> {
 }
 
 fn adjacent(shape: &[usize], (r, c): (usize, usize)) -> Vec<(usize, usize)> {
-    let rs = match r {
-        0 => vec![r + 1],
-        v if v == shape[0] - 1 => vec![v - 1],
-        v => vec![v - 1, v + 1],
-    };
-    let cs = match c {
-        0 => vec![c + 1],
-        v if v == shape[1] - 1 => vec![v - 1],
-        v => vec![v - 1, v + 1],
-    };
+    let rs = [r.checked_sub(1), Some(r + 1).filter(|r| *r < shape[0])];
+    let cs = [c.checked_sub(1), Some(c + 1).filter(|c| *c < shape[1])];
 
     rs.iter()
+        .flatten()
         .map(|r| (*r, c))
-        .chain(cs.iter().map(|c| (r, *c)))
+        .chain(cs.iter().flatten().map(|c| (r, *c)))
         .collect()
 }
@@ -1,8 +1,10 @@
 use itertools::Itertools;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::input::parse_lines;
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Syntax Scoring";
 
 pub struct Problem;
 
@@ -11,11 +13,16 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader.lines().map(|x| x.unwrap()).collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, |line| {
+            match line.char_indices().find(|(_, c)| !"()[]{}<>".contains(*c)) {
+                Some((i, _)) => Err(ParseError::at(line, &line[i..], "a bracket")),
+                None => Ok(line.to_string()),
+            }
+        })
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let result = input
             .iter()
             .map(|line| corrupted_score(line))
@@ -25,7 +32,7 @@ impl Solver for Problem {
         Ok(result)
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         let result = input
             .iter()
             .map(|line| check(line))
@@ -46,6 +53,9 @@ impl Solver for Problem {
             .sorted()
             .collect_vec();
 
+        if result.is_empty() {
+            return Err(SolverError::NoSolution("no line is incomplete".to_string()));
+        }
         Ok(result[result.len() / 2])
     }
 }
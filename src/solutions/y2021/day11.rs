@@ -1,9 +1,11 @@
 use ndarray::Array2;
 
-use super::Solver;
+use crate::solutions::input::parse_digit_grid;
+use crate::solutions::{checkpoint, ParseError, Solver, SolverError};
 use itertools::iproduct;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Dumbo Octopus";
 
 pub struct Problem;
 
@@ -12,17 +14,11 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines: Vec<Vec<u8>> = file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.bytes().map(|c| c - b'0').collect::<Vec<u8>>())
-            .collect();
-
-        ndarray::Array2::from_shape_fn((lines.len(), lines[0].len()), |(r, c)| lines[r][c])
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_digit_grid(file_reader)
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let mut field = input.clone();
         let mut total_flashes: usize = 0;
 
@@ -48,13 +44,14 @@ impl Solver for Problem {
         Ok(total_flashes)
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         let mut field = input.clone();
         let mut total_flashes: usize = 0;
 
         let shape = input.shape();
 
         for i in 1.. {
+            checkpoint()?;
             for (r, c) in iproduct!(0..shape[0], 0..shape[1]) {
                 increase_cell(&mut field, shape, (r, c))
             }
@@ -79,32 +76,29 @@ impl Solver for Problem {
     }
 }
 
-fn increase_cell(field: &mut Array2<u8>, shape: &[usize], (r, c): (usize, usize)) {
-    if field[[r, c]] == 10 {
-        return;
-    }
-    field[[r, c]] += 1;
+/// Raises a cell's energy, along with every neighbour of each cell that
+/// flashes. Uses its own stack, as a chain of flashes across a large grid
+/// would overflow the thread's.
+fn increase_cell(field: &mut Array2<u8>, shape: &[usize], start: (usize, usize)) {
+    let mut pending = vec![start];
 
-    if field[[r, c]] == 10 {
-        for pos in adjacent(shape, (r, c)) {
-            increase_cell(field, shape, pos);
+    while let Some((r, c)) = pending.pop() {
+        if field[[r, c]] == 10 {
+            continue;
+        }
+        field[[r, c]] += 1;
+
+        if field[[r, c]] == 10 {
+            pending.extend(adjacent(shape, (r, c)));
         }
     }
 }
 
 fn adjacent(shape: &[usize], (r, c): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
-    let rs = match r {
-        0 => vec![r, r + 1],
-        v if v == shape[0] - 1 => vec![v - 1, v],
-        v => vec![v - 1, v, v + 1],
-    };
-    let cs = match c {
-        0 => vec![c, c + 1],
-        v if v == shape[1] - 1 => vec![v - 1, c],
-        v => vec![v - 1, c, v + 1],
-    };
-
-    itertools::iproduct!(rs.into_iter(), cs.into_iter()).filter(move |p| !(p.0 == r && p.1 == c))
+    let rs = r.saturating_sub(1)..=(r + 1).min(shape[0] - 1);
+    let cs = c.saturating_sub(1)..=(c + 1).min(shape[1] - 1);
+
+    itertools::iproduct!(rs, cs).filter(move |p| !(p.0 == r && p.1 == c))
 }
 
 // fn print_field(arr: &Array2<u8>) {
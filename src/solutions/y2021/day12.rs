@@ -1,8 +1,10 @@
-use super::Solver;
+use crate::solutions::input::parse_lines;
+use crate::solutions::{ParseError, Solver, SolverError};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Passage Pathing";
 
 pub struct Problem;
 
@@ -11,12 +13,13 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.split("-").map(|x| x.to_owned()).collect_vec())
-            .collect_vec();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(file_reader, |line| {
+            let (from, to) = line
+                .split_once("-")
+                .ok_or_else(|| ParseError::new(line, "'<cave>-<cave>'"))?;
+            Ok(vec![from.to_owned(), to.to_owned()])
+        })?;
 
         let mut index_to_cave = Vec::new();
         let mut cave_to_index = HashMap::new();
@@ -50,15 +53,24 @@ impl Solver for Problem {
             graph[i2].push(i1);
         }
 
-        let start = cave_to_index.get(&"start".to_string()).unwrap().to_owned();
-        let end = cave_to_index.get(&"end".to_string()).unwrap().to_owned();
-        (start, end, graph, smalls)
+        let find_cave = |name: &str| {
+            cave_to_index
+                .get(&name.to_string())
+                .map(|i| i.to_owned())
+                .ok_or_else(|| {
+                    ParseError::end_of_input(format!("a path touching '{}'", name))
+                        .on_line(lines.len())
+                })
+        };
+        let start = find_cave("start")?;
+        let end = find_cave("end")?;
+        Ok((start, end, graph, smalls))
     }
 
     fn solve_first(
         &self,
         (start, end, graph, smalls): &Self::Input,
-    ) -> Result<Self::Output1, String> {
+    ) -> Result<Self::Output1, SolverError> {
         let mut paths_found = HashSet::new();
         find_paths(
             *start,
@@ -77,7 +89,7 @@ impl Solver for Problem {
     fn solve_second(
         &self,
         (start, end, graph, smalls): &Self::Input,
-    ) -> Result<Self::Output2, String> {
+    ) -> Result<Self::Output2, SolverError> {
         let mut paths_found = HashSet::new();
         find_paths(
             *start,
@@ -0,0 +1,188 @@
+use itertools::Itertools;
+use ndarray::Array2;
+use regex::Regex;
+
+use crate::solutions::input::{parse_token, read_lines};
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub const TITLE: &str = "Transparent Origami";
+
+pub struct Problem;
+
+impl Solver for Problem {
+    type Input = (HashSet<Coordinate>, Vec<Fold>);
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let mut coordinates = HashSet::new();
+        let mut folds = Vec::new();
+
+        let lines = read_lines(file_reader)?;
+
+        for (i, line) in lines.iter().enumerate() {
+            if line == "" {
+                continue;
+            }
+
+            if line.starts_with("fold") {
+                folds.push(line.parse().map_err(|err: ParseError| err.on_line(i + 1))?);
+            } else {
+                coordinates.insert(line.parse().map_err(|err: ParseError| err.on_line(i + 1))?);
+            }
+        }
+
+        Ok((coordinates, folds))
+    }
+
+    fn solve_first(&self, (coordinates, folds): &Self::Input) -> Result<Self::Output1, SolverError> {
+        let result = folds
+            .into_iter()
+            .take(1)
+            .fold(coordinates.clone(), apply_fold);
+        Ok(result.len())
+    }
+
+    fn solve_second(&self, (coordinates, folds): &Self::Input) -> Result<Self::Output2, SolverError> {
+        let coordinate_set = folds.into_iter().fold(coordinates.clone(), apply_fold);
+
+        let max = coordinate_set
+            .iter()
+            .fold((0, 0), |(max_x, max_y), c| (max_x.max(c.x), max_y.max(c.y)));
+        let mut result = Array2::<usize>::zeros((max.1 + 1, max.0 + 1));
+
+        for c in &coordinate_set {
+            result[[c.y, c.x]] = 1;
+        }
+
+        read_letters(&result).ok_or_else(|| {
+            SolverError::NoSolution(format!("unreadable letters:\n{}", draw(&result)))
+        })
+    }
+}
+
+/// The capital letters folding spells out, each 4 dots wide and 6 tall,
+/// listed row by row.
+const LETTERS: [(char, &str); 16] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Reads the letters on the folded paper, which sit in 4-dot columns with an
+/// empty one between them.
+fn read_letters(paper: &Array2<usize>) -> Option<String> {
+    let (height, width) = paper.dim();
+    if height != 6 {
+        return None;
+    }
+
+    (0..width.div_ceil(5))
+        .map(|letter| {
+            let dots: String = (0..6)
+                .flat_map(|y| (0..4).map(move |x| (y, letter * 5 + x)))
+                .map(|dot| match paper.get(dot) {
+                    Some(1) => '#',
+                    _ => '.',
+                })
+                .collect();
+            LETTERS
+                .iter()
+                .find(|(_, shape)| *shape == dots)
+                .map(|(c, _)| *c)
+        })
+        .collect()
+}
+
+fn draw(paper: &Array2<usize>) -> String {
+    paper
+        .rows()
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .map(|dot| if *dot == 1 { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .join("\n")
+}
+
+fn apply_fold(coordinates: HashSet<Coordinate>, fold: &Fold) -> HashSet<Coordinate> {
+    coordinates
+        .into_iter()
+        .map(|c| match fold {
+            Fold::X(v) if *v < c.x => Coordinate {
+                x: v - (c.x - v),
+                y: c.y,
+            },
+            Fold::Y(v) if *v < c.y => Coordinate {
+                x: c.x,
+                y: v - (c.y - v),
+            },
+            _ => c,
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+impl FromStr for Fold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static::lazy_static! {
+            static ref LINE_RGX: Regex = Regex::new(r"^fold along (x|y)=(\d+)$").unwrap();
+        }
+
+        let captures = LINE_RGX
+            .captures(s)
+            .ok_or_else(|| ParseError::new(s, "'fold along <x|y>=<value>'"))?;
+        let value = parse_token(s, captures.get(2).unwrap().as_str(), "a fold position")?;
+
+        let result = match &captures[1] {
+            "x" => Fold::X(value),
+            "y" => Fold::Y(value),
+            _ => unreachable!(),
+        };
+        Ok(result)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Hash, Clone, PartialOrd, Ord)]
+pub struct Coordinate {
+    x: usize,
+    y: usize,
+}
+impl FromStr for Coordinate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split(",")
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(s, "'<x>,<y>'"))?;
+
+        Ok(Coordinate {
+            x: parse_token(s, x, "an x coordinate")?,
+            y: parse_token(s, y, "a y coordinate")?,
+        })
+    }
+}
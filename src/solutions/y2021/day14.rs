@@ -1,9 +1,11 @@
 use itertools::Itertools;
 
-use super::Solver;
+use crate::solutions::input::{parse_each, read_lines};
+use crate::solutions::{ParseError, Solver, SolverError};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Extended Polymerization";
 
 pub struct Problem;
 
@@ -12,21 +14,26 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
-
-        let starting_polymer = lines[0].to_string();
-        let mut polymer_rules = HashMap::new();
-
-        for i in 2..lines.len() {
-            let split = lines[i].split(" -> ").collect_vec();
-            polymer_rules.insert(split[0].to_string(), split[1].to_string());
-        }
-
-        (starting_polymer, polymer_rules)
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+
+        let starting_polymer = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input("a polymer template").on_line(1))?
+            .to_string();
+        let polymer_rules = parse_each(lines.get(2..).unwrap_or_default(), 3, |line| {
+            let (pair, element) = line
+                .split_once(" -> ")
+                .ok_or_else(|| ParseError::new(line, "'<pair> -> <element>'"))?;
+            Ok((pair.to_string(), element.to_string()))
+        })?
+        .into_iter()
+        .collect();
+
+        Ok((starting_polymer, polymer_rules))
     }
 
-    fn solve_first(&self, (polymer, rules): &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, (polymer, rules): &Self::Input) -> Result<Self::Output1, SolverError> {
         let mut map = polymer_to_map(polymer);
 
         for _ in 0..10 {
@@ -47,7 +54,7 @@ impl Solver for Problem {
         Ok(max - min)
     }
 
-    fn solve_second(&self, (polymer, rules): &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, (polymer, rules): &Self::Input) -> Result<Self::Output2, SolverError> {
         let mut map = polymer_to_map(polymer);
 
         for _ in 0..40 {
@@ -1,9 +1,11 @@
 use ndarray::Array2;
 use pathfinding::dijkstra;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::input::parse_digit_grid;
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Chiton";
 
 pub struct Problem;
 
@@ -12,21 +14,11 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines: Vec<Vec<usize>> = file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| {
-                line.bytes()
-                    .map(|c| (c - b'0') as usize)
-                    .collect::<Vec<usize>>()
-            })
-            .collect();
-
-        ndarray::Array2::from_shape_fn((lines.len(), lines[0].len()), |(r, c)| lines[r][c])
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_digit_grid(file_reader)
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let shape = input.shape();
         let end = (shape[0] - 1, shape[1] - 1, 0, 0);
 
@@ -43,11 +35,13 @@ impl Solver for Problem {
 
         match path {
             Some((_, risk)) => Ok(risk),
-            None => Err("path not found".to_string()),
+            None => Err(SolverError::NoSolution(
+                "no path to the bottom right".to_string(),
+            )),
         }
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         let shape = input.shape();
         let end = (shape[0] - 1, shape[1] - 1, 4, 4);
 
@@ -64,7 +58,9 @@ impl Solver for Problem {
 
         match path {
             Some((_, risk)) => Ok(risk),
-            None => Err("path not found".to_string()),
+            None => Err(SolverError::NoSolution(
+                "no path to the bottom right".to_string(),
+            )),
         }
     }
 }
@@ -0,0 +1,226 @@
+use itertools::Itertools;
+
+use crate::solutions::input::read_lines;
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Packet Decoder";
+
+pub struct Problem;
+
+#[derive(Debug)]
+pub enum PacketPayload {
+    Literal(usize),
+    Operation(Vec<Packet>),
+}
+#[derive(Debug)]
+pub struct Packet {
+    pub version: u8,
+    pub id: u8,
+    pub payload: PacketPayload,
+}
+
+impl Solver for Problem {
+    type Input = Packet;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+        let line = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input("a hexadecimal transmission").on_line(1))?;
+        let values = line
+            .char_indices()
+            .map(|(i, c)| match c.to_digit(16) {
+                Some(v) => Ok(v as u8),
+                None => Err(ParseError::at(line, &line[i..], "a hexadecimal digit").on_line(1)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut reader = BinaryReader::new(values);
+
+        parse_packet(&mut reader).map_err(|err| err.on_line(1))
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
+        Ok(sum_version(input))
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
+        calculate_value(input)
+    }
+}
+
+pub fn sum_version(packet: &Packet) -> usize {
+    let mut total = packet.version as usize;
+
+    if let PacketPayload::Operation(subpackets) = &packet.payload {
+        for subpacket in subpackets {
+            total += sum_version(subpacket);
+        }
+    }
+
+    return total;
+}
+
+pub fn calculate_value(packet: &Packet) -> Result<usize, SolverError> {
+    match &packet.payload {
+        PacketPayload::Literal(v) => Ok(*v),
+        PacketPayload::Operation(subpackets) => {
+            let v: Vec<usize> = subpackets.iter().map(calculate_value).try_collect()?;
+            let empty = || {
+                SolverError::invalid_input(format!(
+                    "operator packet of type {} has no sub-packets",
+                    packet.id
+                ))
+            };
+            if packet.id >= 5 && v.len() != 2 {
+                return Err(SolverError::invalid_input(format!(
+                    "comparison packet has {} sub-packets instead of 2",
+                    v.len()
+                )));
+            }
+            Ok(match packet.id {
+                0 => v
+                    .iter()
+                    .try_fold(0usize, |acc, v| acc.checked_add(*v))
+                    .ok_or_else(|| SolverError::overflow("sum packet"))?,
+                1 => v
+                    .iter()
+                    .try_fold(1usize, |acc, v| acc.checked_mul(*v))
+                    .ok_or_else(|| SolverError::overflow("product packet"))?,
+                2 => *v.iter().min().ok_or_else(empty)?,
+                3 => *v.iter().max().ok_or_else(empty)?,
+                5 => {
+                    if v[0] > v[1] {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                6 => {
+                    if v[0] < v[1] {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                7 => {
+                    if v[0] == v[1] {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                _ => unreachable!(),
+            })
+        }
+    }
+}
+
+fn parse_packet(reader: &mut BinaryReader) -> Result<Packet, ParseError> {
+    let version = reader.next_u8(3)?;
+    let id = reader.next_u8(3)?;
+
+    let payload = match id {
+        4 => PacketPayload::Literal(parse_literal(reader)?),
+        _ => PacketPayload::Operation(parse_subpackets(reader)?),
+    };
+
+    Ok(Packet {
+        version,
+        id,
+        payload,
+    })
+}
+
+fn parse_literal(reader: &mut BinaryReader) -> Result<usize, ParseError> {
+    let mut result = 0;
+
+    loop {
+        let has_more = reader.next_u8(1)? == 1;
+        let v = reader.next_u8(4)?;
+        result = (result << 4) | (v as usize);
+
+        if !has_more {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+fn parse_subpackets(reader: &mut BinaryReader) -> Result<Vec<Packet>, ParseError> {
+    let mut result = Vec::new();
+
+    match reader.next_u8(1)? {
+        0 => {
+            let len = reader.next_usize(15)?;
+            let remaining = reader.remaining();
+            let end = remaining.checked_sub(len).ok_or_else(|| {
+                ParseError::end_of_input(format!("{} more bits of a packet", len - remaining))
+            })?;
+            while reader.remaining() > end {
+                result.push(parse_packet(reader)?);
+            }
+        }
+        1 => {
+            let n = reader.next_usize(11)?;
+            for _ in 0..n {
+                result.push(parse_packet(reader)?);
+            }
+        }
+        _ => unreachable!(),
+    }
+
+    Ok(result)
+}
+
+struct BinaryReader {
+    data: Vec<u8>,
+    pointer: usize,
+    bit: u8,
+}
+// Assumes values of u4.
+impl BinaryReader {
+    fn new(data: Vec<u8>) -> Self {
+        BinaryReader {
+            data,
+            pointer: 0,
+            bit: 0,
+        }
+    }
+    fn remaining(&self) -> usize {
+        (self.data.len() - self.pointer) * 4 - (self.bit as usize)
+    }
+    fn next_bit(&mut self) -> u8 {
+        let v = self.data[self.pointer];
+        let ret = (v >> (3 - self.bit)) & 0x01;
+        if self.bit == 3 {
+            self.bit = 0;
+            self.pointer += 1;
+        } else {
+            self.bit += 1;
+        }
+
+        ret
+    }
+    fn next_u8(&mut self, n: u8) -> Result<u8, ParseError> {
+        Ok(self.next_usize(n as usize)? as u8)
+    }
+    /// Reads the next `n` bits, failing if the transmission ends first.
+    fn next_usize(&mut self, n: usize) -> Result<usize, ParseError> {
+        if self.remaining() < n {
+            return Err(ParseError::end_of_input(format!(
+                "{} more bits of a packet",
+                n
+            )));
+        }
+        let mut v = 0;
+        for _ in 0..n {
+            v = (v << 1) | (self.next_bit() as usize)
+        }
+        Ok(v)
+    }
+}
@@ -1,8 +1,10 @@
 use regex::Regex;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::input::{parse_token, read_lines};
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Trick Shot";
 
 pub struct Problem;
 
@@ -11,20 +13,26 @@ impl Solver for Problem {
     type Output1 = isize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let line = file_reader.lines().map(|x| x.unwrap()).next().unwrap();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+        let line = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input("a target area").on_line(1))?;
 
         let regex = Regex::new(r"target area: x=(-?\d+)..(-?\d+), y=(-?\d+)..(-?\d+)").unwrap();
 
-        let captures = regex.captures(&line).unwrap();
+        let captures = regex.captures(line).ok_or_else(|| {
+            ParseError::new(line, "'target area: x=<min>..<max>, y=<min>..<max>'").on_line(1)
+        })?;
+        let bound = |i: usize| {
+            parse_token(line, captures.get(i).unwrap().as_str(), "an integer bound")
+                .map_err(|err| err.on_line(1))
+        };
 
-        (
-            (captures[1].parse().unwrap(), captures[2].parse().unwrap()),
-            (captures[3].parse().unwrap(), captures[4].parse().unwrap()),
-        )
+        Ok(((bound(1)?, bound(2)?), (bound(3)?, bound(4)?)))
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let (_, y) = *input;
 
         // => Y is independent from X
@@ -44,7 +52,7 @@ impl Solver for Problem {
         Ok(max_y)
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         let (x, y) = *input;
 
         // Now we know that y can go from straight_into_it up to max_v0 to reach the point.
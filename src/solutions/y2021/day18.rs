@@ -1,6 +1,8 @@
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::input::parse_lines;
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Snailfish";
 
 pub struct Problem;
 
@@ -9,25 +11,29 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
         let mut all_nodes = Vec::new();
 
-        let root_nodes = file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| {
-                let (r, _) = parse_subnode(&line, &mut all_nodes);
-                r
-            })
-            .collect();
+        let root_nodes = parse_lines(file_reader, |line| {
+            let (r, rest) = parse_subnode(line, line, &mut all_nodes)?;
+            if !rest.is_empty() {
+                return Err(ParseError::at(line, rest, "the end of the line"));
+            }
+            Ok(r)
+        })?;
 
-        (all_nodes, root_nodes)
+        Ok((all_nodes, root_nodes))
     }
 
-    fn solve_first(&self, (all_nodes, root_nodes): &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(
+        &self,
+        (all_nodes, root_nodes): &Self::Input,
+    ) -> Result<Self::Output1, SolverError> {
         let mut all_nodes = all_nodes.clone();
 
-        let mut root_id = root_nodes[0];
+        let mut root_id = *root_nodes
+            .first()
+            .ok_or_else(|| SolverError::invalid_input("there are no snailfish numbers"))?;
 
         for i in 1..root_nodes.len() {
             let id = root_nodes[i];
@@ -39,7 +45,10 @@ impl Solver for Problem {
         Ok(magnitude(&all_nodes, root_id))
     }
 
-    fn solve_second(&self, (all_nodes, root_nodes): &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(
+        &self,
+        (all_nodes, root_nodes): &Self::Input,
+    ) -> Result<Self::Output2, SolverError> {
         let total_nodes = root_nodes.len();
 
         let mut result = 0;
@@ -78,16 +87,29 @@ pub struct Node {
     node_type: NodeType,
 }
 
-fn parse_subnode<'a>(s: &'a str, tree: &mut Vec<Node>) -> (usize, &'a str) {
-    if s.starts_with("[") {
-        let (left, s_left) = parse_subnode(&s[1..], tree);
-        let (right, s_right) = parse_subnode(&s_left[1..], tree);
+fn parse_subnode<'a>(
+    line: &str,
+    s: &'a str,
+    tree: &mut Vec<Node>,
+) -> Result<(usize, &'a str), ParseError> {
+    if let Some(s) = s.strip_prefix("[") {
+        let (left, s_left) = parse_subnode(line, s, tree)?;
+        let s_left = s_left
+            .strip_prefix(",")
+            .ok_or_else(|| ParseError::at(line, s_left, "','"))?;
+        let (right, s_right) = parse_subnode(line, s_left, tree)?;
+        let s_right = s_right
+            .strip_prefix("]")
+            .ok_or_else(|| ParseError::at(line, s_right, "']'"))?;
 
         let id = sum(tree, left, right);
 
-        (id, &s_right[1..])
+        Ok((id, s_right))
     } else {
-        let value = s[..1].parse().unwrap();
+        let value = s
+            .get(..1)
+            .and_then(|digit| digit.parse().ok())
+            .ok_or_else(|| ParseError::at(line, s, "a regular number or '['"))?;
 
         let id = tree.len();
         let node = Node {
@@ -96,7 +118,7 @@ fn parse_subnode<'a>(s: &'a str, tree: &mut Vec<Node>) -> (usize, &'a str) {
         };
         tree.push(node);
 
-        (id, &s[1..])
+        Ok((id, &s[1..]))
     }
 }
 
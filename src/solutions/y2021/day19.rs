@@ -1,14 +1,15 @@
 use itertools::Itertools;
 use queues::{Buffer, IsQueue};
 
-use super::Solver;
+use crate::solutions::input::{parse_token, read_lines};
+use crate::solutions::{checkpoint, ParseError, Solver, SolverError};
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::num::ParseIntError;
+use std::io::BufRead;
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 
+pub const TITLE: &str = "Beacon Scanner";
+
 pub struct Problem;
 
 /*
@@ -22,13 +23,13 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).skip(1);
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
 
         let mut result = Vec::new();
         let mut active = Vec::new();
 
-        for l in lines {
+        for (i, l) in lines.iter().enumerate().skip(1) {
             if l == "" {
                 continue;
             }
@@ -36,24 +37,24 @@ impl Solver for Problem {
                 result.push(active);
                 active = Vec::new();
             } else {
-                active.push(l.parse().unwrap());
+                active.push(l.parse().map_err(|err: ParseError| err.on_line(i + 1))?);
             }
         }
         result.push(active);
 
-        return result;
+        Ok(result)
     }
 
-    fn solve_first(&self, scanners: &Self::Input) -> Result<Self::Output1, String> {
-        let matches = match_scanners(&scanners);
+    fn solve_first(&self, scanners: &Self::Input) -> Result<Self::Output1, SolverError> {
+        let matches = match_scanners(&scanners)?;
 
         let merged = merge_scanners(&scanners, &matches, 0);
 
         Ok(merged.len())
     }
 
-    fn solve_second(&self, scanners: &Self::Input) -> Result<Self::Output2, String> {
-        let matches = match_scanners(&scanners);
+    fn solve_second(&self, scanners: &Self::Input) -> Result<Self::Output2, SolverError> {
+        let matches = match_scanners(&scanners)?;
 
         let positions = get_scanner_positions(&scanners, &matches, 0);
 
@@ -101,7 +102,9 @@ fn merge_scanners(
     result
 }
 
-fn match_scanners(scanners: &Vec<Vec<Position>>) -> HashMap<usize, Vec<(usize, MatchTransform)>> {
+fn match_scanners(
+    scanners: &Vec<Vec<Position>>,
+) -> Result<HashMap<usize, Vec<(usize, MatchTransform)>>, SolverError> {
     let distances = scanners.iter().map(|s| get_distances(s)).collect_vec();
 
     let mut to_match = Buffer::<usize>::new(distances.len());
@@ -110,6 +113,7 @@ fn match_scanners(scanners: &Vec<Vec<Position>>) -> HashMap<usize, Vec<(usize, M
     let mut matches: HashMap<usize, Vec<(usize, MatchTransform)>> = HashMap::new();
 
     while let Ok(i) = to_match.remove() {
+        checkpoint()?;
         let v = matches.entry(i).or_insert(Vec::new());
 
         for (i_match, transform) in find_matches(&distances, scanners, i, &unmatched) {
@@ -120,9 +124,14 @@ fn match_scanners(scanners: &Vec<Vec<Position>>) -> HashMap<usize, Vec<(usize, M
         }
     }
 
-    assert!(unmatched.len() == 0, "some scanners unmatched");
+    if !unmatched.is_empty() {
+        return Err(SolverError::NoSolution(format!(
+            "{} scanners overlap with no other",
+            unmatched.len()
+        )));
+    }
 
-    matches
+    Ok(matches)
 }
 
 fn find_matches(
@@ -319,15 +328,18 @@ impl Sub for Position {
 }
 
 impl FromStr for Position {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let values = s.split(",").collect_vec();
+        let (x, y, z) = s
+            .split(",")
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(s, "'<x>,<y>,<z>'"))?;
 
         Ok(Position {
-            x: values[0].parse()?,
-            y: values[1].parse()?,
-            z: values[2].parse()?,
+            x: parse_token(s, x, "an x coordinate")?,
+            y: parse_token(s, y, "a y coordinate")?,
+            z: parse_token(s, z, "a z coordinate")?,
         })
     }
 }
@@ -1,9 +1,9 @@
-use itertools::Itertools;
-
-use super::Solver;
+use crate::solutions::input::read_lines;
+use crate::solutions::{ParseError, Solver, SolverError};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Trench Map";
 
 pub struct Problem;
 
@@ -22,24 +22,32 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
 
-        let enhancement = lines[0].chars().map(|c| c == '#').collect_vec();
+        let first = lines
+            .first()
+            .ok_or_else(|| ParseError::end_of_input("an enhancement algorithm").on_line(1))?;
+        let enhancement = parse_pixels(first).map_err(|err| err.on_line(1))?;
+        if enhancement.len() != 512 {
+            return Err(ParseError::new(first, "512 enhancement pixels").on_line(1));
+        }
         let mut lit = HashSet::new();
-        let max = (lines[2].len() as isize - 1, (lines.len() - 2) as isize - 1);
+        let width = lines.get(2).map_or(0, |line| line.len());
+        let max = (width as isize - 1, lines.len() as isize - 2 - 1);
         for i in 2..lines.len() {
             let y = (i - 2) as isize;
             let line = &lines[i];
-            lit.extend(line.char_indices().filter_map(|(x, c)| {
-                if c == '#' {
+            let pixels = parse_pixels(line).map_err(|err| err.on_line(i + 1))?;
+            lit.extend(pixels.into_iter().enumerate().filter_map(|(x, c)| {
+                if c {
                     Some((x as isize, y))
                 } else {
                     None
                 }
             }))
         }
-        (
+        Ok((
             enhancement,
             Image {
                 lit,
@@ -47,10 +55,10 @@ impl Solver for Problem {
                 min: (0, 0),
                 inverted: false,
             },
-        )
+        ))
     }
 
-    fn solve_first(&self, (enhancement, image): &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, (enhancement, image): &Self::Input) -> Result<Self::Output1, SolverError> {
         let mut last_image = image.clone();
 
         // print_image(&last_image);
@@ -62,7 +70,7 @@ impl Solver for Problem {
         Ok(last_image.lit.len())
     }
 
-    fn solve_second(&self, (enhancement, image): &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, (enhancement, image): &Self::Input) -> Result<Self::Output2, SolverError> {
         let mut last_image = image.clone();
 
         for _ in 0..50 {
@@ -91,6 +99,16 @@ fn _print_image(image: &Image) {
     println!("");
 }
 
+fn parse_pixels(line: &str) -> Result<Vec<bool>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::at(line, &line[i..], "'#' or '.'")),
+        })
+        .collect()
+}
+
 fn enhance(enhancement: &Vec<bool>, image: &Image) -> Image {
     let inverts = enhancement[0];
 
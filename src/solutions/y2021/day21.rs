@@ -1,9 +1,9 @@
-use itertools::Itertools;
-
-use super::Solver;
+use crate::solutions::input::{parse_lines, parse_token};
+use crate::solutions::{ParseError, Solver, SolverError};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Dirac Dice";
 
 pub struct Problem;
 
@@ -12,18 +12,20 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let vec = file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.split(": ").collect_vec()[1].to_string())
-            .map(|line| line.parse())
-            .map(|x| x.unwrap())
-            .collect_vec();
-        (vec[0], vec[1])
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let vec = parse_lines(file_reader, |line| {
+            let (_, position) = line.split_once(": ").ok_or_else(|| {
+                ParseError::new(line, "'Player <n> starting position: <position>'")
+            })?;
+            parse_token(line, position, "a starting position")
+        })?;
+        match vec[..] {
+            [p1, p2] => Ok((p1, p2)),
+            _ => Err(ParseError::end_of_input("exactly two players").on_line(vec.len())),
+        }
     }
 
-    fn solve_first(&self, (p1, p2): &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, (p1, p2): &Self::Input) -> Result<Self::Output1, SolverError> {
         let mut positions = [*p1 - 1, *p2 - 1];
         let mut scores: [usize; 2] = [0, 0];
         let mut next_roll = 0;
@@ -49,7 +51,7 @@ impl Solver for Problem {
         }
     }
 
-    fn solve_second(&self, (p1, p2): &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, (p1, p2): &Self::Input) -> Result<Self::Output2, SolverError> {
         let position = [*p1 - 1, *p2 - 1];
         let mut cache = HashMap::new();
 
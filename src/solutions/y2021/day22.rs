@@ -1,13 +1,14 @@
-use super::Solver;
+use crate::solutions::input::{parse_lines, parse_token};
+use crate::solutions::{checkpoint, ParseError, Solver, SolverError};
 use regex::Regex;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::num::ParseIntError;
+use std::io::BufRead;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+pub const TITLE: &str = "Reactor Reboot";
+
 pub struct Problem;
 
 impl Solver for Problem {
@@ -15,19 +16,15 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = isize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| line.parse())
-            .map(|x| x.unwrap())
-            .collect()
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_lines(file_reader, str::parse)
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let mut cuboids = HashSet::new();
 
         for command in input {
+            checkpoint()?;
             for x in cap_range(&command.cuboid.x) {
                 for y in cap_range(&command.cuboid.y) {
                     for z in cap_range(&command.cuboid.z) {
@@ -44,10 +41,11 @@ impl Solver for Problem {
         Ok(cuboids.len())
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         let mut space = HashSet::new();
 
         for command in input {
+            checkpoint()?;
             if command.on {
                 add_cuboid(&mut space, &command.cuboid);
             } else {
@@ -70,14 +68,14 @@ impl Solver for Problem {
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
-struct Cuboid {
-    x: RangeInclusive<isize>,
-    y: RangeInclusive<isize>,
-    z: RangeInclusive<isize>,
+pub struct Cuboid {
+    pub x: RangeInclusive<isize>,
+    pub y: RangeInclusive<isize>,
+    pub z: RangeInclusive<isize>,
 }
 
 impl Cuboid {
-    fn contains(&self, cuboid: &Cuboid) -> bool {
+    pub fn contains(&self, cuboid: &Cuboid) -> bool {
         self.x.contains(cuboid.x.start())
             && self.x.contains(cuboid.x.end())
             && self.y.contains(cuboid.y.start())
@@ -86,7 +84,7 @@ impl Cuboid {
             && self.z.contains(cuboid.z.end())
     }
 
-    fn intersects(&self, cuboid: &Cuboid) -> Option<Cuboid> {
+    pub fn intersects(&self, cuboid: &Cuboid) -> Option<Cuboid> {
         let result = Cuboid {
             x: *(self.x.start().max(cuboid.x.start()))..=*(self.x.end().min(cuboid.x.end())),
             y: *(self.y.start().max(cuboid.y.start()))..=*(self.y.end().min(cuboid.y.end())),
@@ -154,26 +152,29 @@ fn cap_range(range: &RangeInclusive<isize>) -> RangeInclusive<isize> {
 
 #[derive(Debug)]
 pub struct Command {
-    on: bool,
-    cuboid: Cuboid,
+    pub on: bool,
+    pub cuboid: Cuboid,
 }
 
 impl FromStr for Command {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         lazy_static::lazy_static! {
-            static ref LINE_RGX: Regex = Regex::new(r"(on|off) x=(-?\d+)..(-?\d+),y=(-?\d+)..(-?\d+),z=(-?\d+)..(-?\d+)").unwrap();
+            static ref LINE_RGX: Regex = Regex::new(r"^(on|off) x=(-?\d+)..(-?\d+),y=(-?\d+)..(-?\d+),z=(-?\d+)..(-?\d+)$").unwrap();
         }
 
-        let captures = LINE_RGX.captures(s).unwrap();
+        let captures = LINE_RGX.captures(s).ok_or_else(|| {
+            ParseError::new(s, "'<on|off> x=<min>..<max>,y=<min>..<max>,z=<min>..<max>'")
+        })?;
+        let bound = |i| parse_token(s, captures.get(i).unwrap().as_str(), "an integer bound");
 
         let result = Command {
             on: captures[1].to_string() == "on",
             cuboid: Cuboid {
-                x: captures[2].parse()?..=captures[3].parse()?,
-                y: captures[4].parse()?..=captures[5].parse()?,
-                z: captures[6].parse()?..=captures[7].parse()?,
+                x: bound(2)?..=bound(3)?,
+                y: bound(4)?..=bound(5)?,
+                z: bound(6)?..=bound(7)?,
             },
         };
         Ok(result)
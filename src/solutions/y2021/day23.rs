@@ -1,9 +1,11 @@
 use itertools::Itertools;
 use pathfinding::dijkstra;
 
-use super::Solver;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use crate::solutions::input::{parse_each, read_lines};
+use crate::solutions::{cancelled, checkpoint, ParseError, Solver, SolverError};
+use std::io::BufRead;
+
+pub const TITLE: &str = "Amphipod";
 
 pub struct Problem;
 
@@ -12,28 +14,39 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader
-            .lines()
-            .map(|x| x.unwrap())
-            .map(|line| {
-                line.split("")
-                    .filter(|c| *c != "" && *c != "#" && *c != "." && *c != " ")
-                    .map(|c| c.chars().next().unwrap())
-                    .collect_vec()
-            })
-            .filter(|x| x.len() > 0)
-            .collect_vec();
-
-        [
-            [0, 1].map(|i| lines[i][0]),
-            [0, 1].map(|i| lines[i][1]),
-            [0, 1].map(|i| lines[i][2]),
-            [0, 1].map(|i| lines[i][3]),
-        ]
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
+        let rooms = parse_each(&lines, 1, |line| {
+            let amphipods = line
+                .char_indices()
+                .filter(|(_, c)| *c != '#' && *c != '.' && *c != ' ')
+                .map(|(i, c)| match c {
+                    'A'..='D' => Ok(c),
+                    _ => Err(ParseError::at(line, &line[i..], "an amphipod (A-D)")),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            match amphipods.len() {
+                0 | 4 => Ok(amphipods),
+                _ => Err(ParseError::new(line, "4 amphipods, one per room")),
+            }
+        })?
+        .into_iter()
+        .filter(|x| !x.is_empty())
+        .collect_vec();
+
+        if rooms.len() < 2 {
+            return Err(ParseError::end_of_input("2 rows of amphipods").on_line(lines.len()));
+        }
+
+        Ok([
+            [0, 1].map(|i| rooms[i][0]),
+            [0, 1].map(|i| rooms[i][1]),
+            [0, 1].map(|i| rooms[i][2]),
+            [0, 1].map(|i| rooms[i][3]),
+        ])
     }
 
-    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
         let start = Node {
             pods: input
                 .into_iter()
@@ -42,12 +55,12 @@ impl Solver for Problem {
             hallway: [EMPTY; 11],
         };
 
-        let (_, cost) = dijkstra(&start, adjacent, is_solved).unwrap();
+        let (_, cost) = search(&start)?;
 
         Ok(cost)
     }
 
-    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, String> {
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
         let extra = [['D', 'D'], ['C', 'B'], ['B', 'A'], ['A', 'C']];
 
         let start = Node {
@@ -66,12 +79,24 @@ impl Solver for Problem {
             hallway: [EMPTY; 11],
         };
 
-        let (_, cost) = dijkstra(&start, adjacent, is_solved).unwrap();
+        let (_, cost) = search(&start)?;
 
         Ok(cost)
     }
 }
 
+/// The cheapest way to sort the amphipods from `start`. The search runs out of
+/// moves once the part is cancelled.
+fn search(start: &Node) -> Result<(Vec<Node>, usize), SolverError> {
+    let path = dijkstra(
+        start,
+        |node| if cancelled() { vec![] } else { adjacent(node) },
+        is_solved,
+    );
+    checkpoint()?;
+    path.ok_or_else(|| SolverError::NoSolution("no way to sort the amphipods".to_string()))
+}
+
 fn char_to_pod(c: char) -> u8 {
     match c {
         'A' => 0,
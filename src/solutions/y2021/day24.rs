@@ -0,0 +1,168 @@
+use itertools::Itertools;
+
+use crate::solutions::input::{parse_lines, parse_token};
+use crate::solutions::{ParseError, Solver, SolverError};
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub const TITLE: &str = "Arithmetic Logic Unit";
+
+pub struct Problem;
+
+impl Solver for Problem {
+    type Input = Vec<Instruction>;
+    type Output1 = isize;
+    type Output2 = usize;
+
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok(parse_lines(file_reader, |line| {
+            if !line.is_empty() {
+                line.parse().map(Some)
+            } else {
+                Ok(None)
+            }
+        })?
+        .into_iter()
+        .flatten()
+        .collect())
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
+        let mut registers: [isize; 4] = [0; 4];
+
+        // let r = [9, 9, 9, 1, 1, 9, 9, 3, 9, 4, 9, 6, 8, 4];
+        //                 0   1  2  3   4  5  6  7  8 9  10 11  12 13
+        //                 12,10,13,-11,13,-1,10,11,0,10,-5,-16,-7,-11
+        let r = [6, 2, 9, 1, 1, 9, 4, 1, 7, 1, 6, 1, 1, 1];
+        // let r = [1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1];
+        // let r = [1, 1, 9, 1, 1, 9, 9, 3, 9, 1, 6, 6, 1, -3];
+        let mut input_value = r.iter();
+        for i in input {
+            match i.clone() {
+                Instruction::Inp(reg) => {
+                    registers[r_to_i(reg)] = *input_value.next().ok_or_else(|| {
+                        SolverError::invalid_input("the program reads more than 14 digits")
+                    })?;
+                }
+                Instruction::Add(reg, val) => registers[r_to_i(reg)] += get_val(val, &registers),
+                Instruction::Mul(reg, val) => registers[r_to_i(reg)] *= get_val(val, &registers),
+                Instruction::Div(reg, val) => {
+                    let val = get_val(val, &registers);
+                    if val == 0 {
+                        return Err(SolverError::invalid_input("the program divides by 0"));
+                    }
+                    registers[r_to_i(reg)] /= val;
+                }
+                Instruction::Mod(reg, val) => {
+                    let val = get_val(val, &registers);
+                    if registers[r_to_i(reg)] < 0 || val <= 0 {
+                        return Err(SolverError::invalid_input(format!(
+                            "the program takes {} modulo {}",
+                            registers[r_to_i(reg)],
+                            val
+                        )));
+                    }
+                    registers[r_to_i(reg)] %= val;
+                }
+                Instruction::Eql(reg, val) => {
+                    registers[r_to_i(reg)] = if registers[r_to_i(reg)] == get_val(val, &registers) {
+                        1
+                    } else {
+                        0
+                    }
+                }
+            }
+        }
+        Ok(registers[3])
+    }
+
+    fn solve_second(&self, _: &Self::Input) -> Result<Self::Output2, SolverError> {
+        Err(SolverError::NotImplemented)
+    }
+}
+
+fn r_to_i(reg: char) -> usize {
+    reg.to_digit(36).unwrap() as usize - 'w'.to_digit(36).unwrap() as usize
+}
+
+fn get_val(val: Value, regs: &[isize; 4]) -> isize {
+    match val {
+        Value::Reg(reg) => regs[r_to_i(reg)],
+        Value::Imm(val) => val,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Reg(char),
+    Imm(isize),
+}
+
+impl FromStr for Value {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let regs = ["w", "x", "y", "z"];
+        Ok(if regs.contains(&s) {
+            Value::Reg(s.chars().next().unwrap())
+        } else {
+            Value::Imm(parse_token(s, s, "a register or an integer")?)
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Inp(char),
+    Add(char, Value),
+    Mul(char, Value),
+    Div(char, Value),
+    Mod(char, Value),
+    Eql(char, Value),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s.split_whitespace().collect_vec();
+        let operand = |i: usize, expected: &str| match split.get(i) {
+            Some(token) => Ok(*token),
+            None => Err(ParseError::at(s, &s[s.len()..], expected)),
+        };
+        let register = |i: usize| {
+            let token = operand(i, "a register")?;
+            match token {
+                "w" | "x" | "y" | "z" => Ok(token.chars().next().unwrap()),
+                _ => Err(ParseError::at(
+                    s,
+                    token,
+                    "one of the registers w, x, y or z",
+                )),
+            }
+        };
+        let value = |i: usize| {
+            let token = operand(i, "a register or an integer")?;
+            token
+                .parse::<Value>()
+                .map_err(|_| ParseError::at(s, token, "a register or an integer"))
+        };
+
+        let r = match operand(0, "an instruction")? {
+            "inp" => Instruction::Inp(register(1)?),
+            "add" => Instruction::Add(register(1)?, value(2)?),
+            "mul" => Instruction::Mul(register(1)?, value(2)?),
+            "div" => Instruction::Div(register(1)?, value(2)?),
+            "mod" => Instruction::Mod(register(1)?, value(2)?),
+            "eql" => Instruction::Eql(register(1)?, value(2)?),
+            op => {
+                return Err(ParseError::at(
+                    s,
+                    op,
+                    "one of inp, add, mul, div, mod or eql",
+                ))
+            }
+        };
+        Ok(r)
+    }
+}
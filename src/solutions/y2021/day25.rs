@@ -1,10 +1,9 @@
-use itertools::Itertools;
-
-use super::Solver;
+use crate::solutions::input::read_lines;
+use crate::solutions::{ParseError, Solver, SolverError};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::vec;
+use std::io::BufRead;
+
+pub const TITLE: &str = "Sea Cucumber";
 
 pub struct Problem;
 
@@ -13,16 +12,23 @@ impl Solver for Problem {
     type Output1 = usize;
     type Output2 = usize;
 
-    fn read_input(&self, file_reader: BufReader<&File>) -> Self::Input {
-        let lines = file_reader.lines().map(|x| x.unwrap()).collect_vec();
+    fn read_input(&self, file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let lines = read_lines(file_reader)?;
         let mut down = HashSet::new();
         let mut right = HashSet::new();
         let size = Coord {
-            x: lines[0].len(),
+            x: lines.first().map_or(0, |line| line.chars().count()),
             y: lines.len(),
         };
-        for (y, l) in lines.into_iter().enumerate() {
-            for (x, c) in l.char_indices() {
+        for (y, l) in lines.iter().enumerate() {
+            if l.chars().count() != size.x {
+                let end = l.char_indices().nth(size.x).map_or(l.len(), |(i, _)| i);
+                return Err(
+                    ParseError::at(l, &l[end..], format!("a row of {} cells", size.x))
+                        .on_line(y + 1),
+                );
+            }
+            for (x, (i, c)) in l.char_indices().enumerate() {
                 match c {
                     'v' => {
                         down.insert(Coord { x, y });
@@ -30,22 +36,26 @@ impl Solver for Problem {
                     '>' => {
                         right.insert(Coord { x, y });
                     }
-                    _ => {}
+                    '.' => {}
+                    _ => {
+                        return Err(
+                            ParseError::at(l, &l[i..], "one of 'v', '>' or '.'").on_line(y + 1)
+                        );
+                    }
                 }
             }
         }
 
-        (right, down, size)
+        Ok((right, down, size))
     }
 
-    fn solve_first(&self, (right, down, size): &Self::Input) -> Result<Self::Output1, String> {
+    fn solve_first(&self, (right, down, size): &Self::Input) -> Result<Self::Output1, SolverError> {
         let mut moves = 0;
         let mut right = right.clone();
         let mut down = down.clone();
         loop {
             moves += 1;
             let (r, d, done) = move_cuc(right, down, size);
-            print_cuc(&r, &d, size);
             if done {
                 return Ok(moves);
             }
@@ -54,8 +64,9 @@ impl Solver for Problem {
         }
     }
 
-    fn solve_second(&self, _: &Self::Input) -> Result<Self::Output2, String> {
-        Ok(0)
+    /// Day 25 has no second puzzle: its star comes free with the other 49.
+    fn solve_second(&self, _: &Self::Input) -> Result<Self::Output2, SolverError> {
+        Err(SolverError::NotImplemented)
     }
 }
 
@@ -97,23 +108,6 @@ fn move_cuc(
     (new_right, new_down, done)
 }
 
-fn print_cuc(right: &HashSet<Coord>, down: &HashSet<Coord>, size: &Coord) {
-    for y in (0..size.y) {
-        let mut cs = vec![];
-        for x in (0..size.x) {
-            let coord = Coord { x, y };
-            if right.contains(&coord) {
-                cs.push('>');
-            } else if down.contains(&coord) {
-                cs.push('v');
-            } else {
-                cs.push('.');
-            }
-        }
-        println!("{}", cs.into_iter().collect::<String>());
-    }
-}
-
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Coord {
     x: usize,
@@ -0,0 +1,169 @@
+use crate::download::{DownloadError, Downloader, Http};
+use crate::guesses::{self, Conflict};
+use crate::{answers, solutions};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::error::Error;
+use std::fmt::Display;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// The answer wasn't checked, another one was submitted too recently.
+    RateLimited(Duration),
+    /// The part is already solved, or not unlocked yet.
+    AlreadySolved,
+}
+
+lazy_static! {
+    static ref WAIT: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the answer form posts to.
+    pub fn parse(body: &str) -> Option<Verdict> {
+        if body.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if body.contains("That's not the right answer") {
+            Some(if body.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if body.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if body.contains("You gave an answer too recently") {
+            let wait = WAIT.captures(body).map_or(Duration::ZERO, |captures| {
+                let number = |i| captures.get(i).map_or(0, |m| m.as_str().parse().unwrap());
+                Duration::from_secs(number(1) * 60 + number(2))
+            });
+            Some(Verdict::RateLimited(wait))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "Wrong, the answer is too high"),
+            Verdict::TooLow => write!(f, "Wrong, the answer is too low"),
+            Verdict::Wrong => write!(f, "Wrong answer"),
+            Verdict::RateLimited(wait) => write!(
+                f,
+                "Answered too recently, wait {}s before trying again",
+                wait.as_secs()
+            ),
+            Verdict::AlreadySolved => write!(f, "This part is already solved or still locked"),
+        }
+    }
+}
+
+/// An answer for one part of a day, and where to keep what the site says
+/// about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: isize,
+    pub day: isize,
+    pub part: u8,
+    pub answer: String,
+    /// The part's guesses log.
+    pub guesses: PathBuf,
+    /// The day's answers file, which a correct answer gets recorded in. Days
+    /// without a solver have none.
+    pub answers: Option<PathBuf>,
+}
+
+impl Submission {
+    /// Keeps guesses and answers in the active profile's stores.
+    pub fn new(year: isize, day: isize, part: u8, answer: impl Into<String>) -> Self {
+        Submission {
+            year,
+            day,
+            part,
+            answer: answer.into(),
+            guesses: guesses::guess_file(year, day, part).into(),
+            answers: solutions::find(year, day)
+                .ok()
+                .map(|day| answers::answers_file(day).into()),
+        }
+    }
+
+    /// Fails if earlier guesses already rule the answer out.
+    pub fn check(&self) -> Result<(), SubmitError> {
+        let logged = guesses::load(&self.guesses)
+            .map_err(|err| SubmitError::Io(self.guesses.clone(), err))?;
+        match guesses::check(&logged, &self.answer) {
+            Some(conflict) => Err(SubmitError::KnownWrong(self.answer.clone(), conflict)),
+            None => Ok(()),
+        }
+    }
+
+    /// Submits the answer unless `check` rules it out, logs the verdict and
+    /// records the answer once it's right.
+    pub async fn send<H: Http>(&self, downloader: &Downloader<H>) -> Result<Verdict, SubmitError> {
+        self.check()?;
+        let verdict = downloader
+            .submit(self.year, self.day, self.part, &self.answer)
+            .await?;
+        guesses::record(&self.guesses, &self.answer, &verdict)
+            .map_err(|err| SubmitError::Io(self.guesses.clone(), err))?;
+
+        if let (Verdict::Correct, Some(path)) = (&verdict, &self.answers) {
+            let io_error = |err| SubmitError::Io(path.clone(), err);
+            let mut known = answers::read(path).map_err(io_error)?;
+            known[self.part as usize - 1] = Some(self.answer.clone());
+            answers::write(path, &known).map_err(io_error)?;
+        }
+        Ok(verdict)
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// Earlier guesses already rule the answer out, so it wasn't sent.
+    KnownWrong(String, Conflict),
+    Download(DownloadError),
+    /// The guesses log or answers file couldn't be read or written.
+    Io(PathBuf, io::Error),
+}
+
+impl From<DownloadError> for SubmitError {
+    fn from(err: DownloadError) -> Self {
+        SubmitError::Download(err)
+    }
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::KnownWrong(answer, conflict) => {
+                write!(f, "Not submitting {}: {}", answer, conflict)
+            }
+            SubmitError::Download(err) => write!(f, "{}", err),
+            SubmitError::Io(path, err) => write!(f, "Could not update {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl Error for SubmitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SubmitError::KnownWrong(..) => None,
+            SubmitError::Download(err) => Some(err),
+            SubmitError::Io(_, err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use hello_rust::solutions::Stats;
+use std::time::Duration;
+
+fn millis(samples: &[u64]) -> Vec<Duration> {
+    samples
+        .iter()
+        .map(|ms| Duration::from_millis(*ms))
+        .collect()
+}
+
+#[test]
+fn summarises_an_odd_number_of_samples() {
+    let stats = Stats::from_samples(millis(&[9, 1, 5]));
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(5));
+    assert_eq!(stats.mean, Duration::from_millis(5));
+    // The deviations are 4, 4 and 0 ms: sqrt(32 / 3) ms.
+    assert_eq!(stats.stddev.as_micros(), 3265);
+}
+
+#[test]
+fn takes_the_middle_two_for_an_even_median() {
+    let stats = Stats::from_samples(millis(&[8, 2, 4, 6]));
+    assert_eq!(stats.min, Duration::from_millis(2));
+    assert_eq!(stats.median, Duration::from_millis(5));
+    assert_eq!(stats.mean, Duration::from_millis(5));
+    // sqrt((9 + 1 + 1 + 9) / 4) = sqrt(5) ms.
+    assert_eq!(stats.stddev.as_micros(), 2236);
+}
+
+#[test]
+fn a_single_sample_has_no_spread() {
+    let stats = Stats::from_samples(millis(&[3]));
+    assert_eq!(stats.median, Duration::from_millis(3));
+    assert_eq!(stats.stddev, Duration::ZERO);
+}
@@ -0,0 +1,111 @@
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use hello_rust::calendar::{self, CalendarError, Clock};
+use std::time::Duration;
+
+struct FixedClock(DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+fn utc(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    let time = NaiveDate::from_ymd_opt(2021, month, day)
+        .and_then(|date| date.and_hms_opt(hour, minute, 0))
+        .unwrap();
+    Utc.from_utc_datetime(&time)
+}
+
+fn at(month: u32, day: u32, hour: u32, minute: u32) -> FixedClock {
+    FixedClock(utc(month, day, hour, minute))
+}
+
+#[test]
+fn uses_eastern_date() {
+    // 03:00 UTC on the 6th is still the evening of the 5th in New York.
+    assert_eq!(calendar::current_day(&at(12, 6, 3, 0)), Ok(5));
+    assert_eq!(calendar::current_day(&at(12, 6, 5, 0)), Ok(6));
+}
+
+#[test]
+fn rejects_days_outside_the_event() {
+    let error = |month, day| {
+        Err(CalendarError::OutsideEvent(
+            NaiveDate::from_ymd_opt(2021, month, day).unwrap(),
+        ))
+    };
+    assert_eq!(calendar::current_day(&at(11, 30, 12, 0)), error(11, 30));
+    assert_eq!(calendar::current_day(&at(12, 26, 12, 0)), error(12, 26));
+    // Still November 30th in New York.
+    assert_eq!(calendar::current_day(&at(12, 1, 4, 59)), error(11, 30));
+}
+
+#[test]
+fn next_day_is_tomorrows_puzzle() {
+    assert_eq!(calendar::next_day(&at(12, 1, 4, 59)), Ok((2021, 1)));
+    assert_eq!(calendar::next_day(&at(12, 7, 12, 0)), Ok((2021, 8)));
+    assert_eq!(
+        calendar::next_day(&at(12, 25, 12, 0)),
+        Err(CalendarError::EventOver(2021))
+    );
+    // Reports the date it is, not the date tomorrow would be.
+    assert_eq!(
+        calendar::next_day(&at(11, 15, 12, 0)),
+        Err(CalendarError::OutsideEvent(
+            NaiveDate::from_ymd_opt(2021, 11, 15).unwrap()
+        ))
+    );
+}
+
+#[test]
+fn next_day_takes_the_year_from_the_clock() {
+    let time = NaiveDate::from_ymd_opt(2023, 12, 1)
+        .and_then(|date| date.and_hms_opt(4, 0, 0))
+        .unwrap();
+    let clock = FixedClock(Utc.from_utc_datetime(&time));
+    assert_eq!(calendar::next_day(&clock), Ok((2023, 1)));
+}
+
+#[test]
+fn waiting_stops_once_the_event_is_over() {
+    assert_eq!(
+        calendar::wait_for(&at(12, 7, 12, 0), 2021, 8),
+        Ok(Some(Duration::from_secs(17 * 3600)))
+    );
+    // Days that already unlocked come right away while the event runs.
+    assert_eq!(calendar::wait_for(&at(12, 7, 12, 0), 2021, 3), Ok(None));
+    assert_eq!(
+        calendar::wait_for(&at(12, 25, 5, 1), 2021, 25),
+        Err(CalendarError::EventOver(2021))
+    );
+    assert_eq!(
+        calendar::wait_for(&at(12, 7, 12, 0), 2020, 8),
+        Err(CalendarError::EventOver(2020))
+    );
+}
+
+#[test]
+fn puzzles_unlock_at_eastern_midnight() {
+    assert_eq!(calendar::unlock_time(2021, 8), Ok(utc(12, 8, 5, 0)));
+    assert_eq!(
+        calendar::until_unlock(&at(12, 8, 4, 30), 2021, 8),
+        Ok(Some(Duration::from_secs(30 * 60)))
+    );
+    assert_eq!(
+        calendar::until_unlock(&at(12, 8, 5, 0), 2021, 8),
+        Ok(Some(Duration::ZERO))
+    );
+    assert_eq!(calendar::until_unlock(&at(12, 8, 5, 1), 2021, 8), Ok(None));
+}
+
+#[test]
+fn days_outside_the_event_never_unlock() {
+    for day in [0, 26, 40, -1] {
+        assert_eq!(
+            calendar::unlock_time(2021, day),
+            Err(CalendarError::NoSuchDay(day))
+        );
+    }
+    assert!(calendar::until_unlock(&at(12, 1, 0, 0), 2021, 40).is_err());
+}
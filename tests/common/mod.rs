@@ -0,0 +1,101 @@
+//! Helpers shared by the integration tests.
+
+// Each test crate compiles this module and uses only some of it.
+#![allow(dead_code)]
+
+use hello_rust::download::{Downloader, Http, Response};
+use std::error::Error;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// An empty directory under the system temp directory that no other test
+/// uses, removed again when it's dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// `name` keeps the directories of the tests in one process apart, and
+    /// the process id those of concurrent runs.
+    pub fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("hello-rust-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A request the fake site got: its URL, and the form fields if it was a post.
+pub type Request = (String, Option<Vec<(String, String)>>);
+
+/// Answers every request with `status` and `body`, remembering what was asked.
+pub struct Site {
+    status: u16,
+    body: &'static str,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl Site {
+    pub fn new(status: u16, body: &'static str) -> Self {
+        Site {
+            status,
+            body,
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// A downloader for the site that doesn't wait between requests.
+    pub fn downloader(&self) -> Downloader<&Site> {
+        Downloader::new(self, "http://example.test").with_interval(Duration::ZERO)
+    }
+
+    fn respond(&self, request: Request) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        self.requests.lock().unwrap().push(request);
+        Ok(Response {
+            status: self.status,
+            body: self.body.to_string(),
+        })
+    }
+}
+
+impl Http for &Site {
+    async fn get(&self, url: &str) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        self.respond((url.to_string(), None))
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        let form = form
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        self.respond((url.to_string(), Some(form)))
+    }
+}
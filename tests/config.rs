@@ -0,0 +1,140 @@
+use hello_rust::config::{Color, Config, ConfigError, Section};
+use hello_rust::output::Format;
+use std::path::PathBuf;
+
+const CONFIG: &str = r#"
+token = "main-session"
+year = 2020
+color = "never"
+
+[profiles.alice]
+token = "alice-session"
+year = 2021
+format = "json"
+
+[profiles.bob]
+inputs = "/data/bob"
+"#;
+
+#[test]
+fn uses_built_in_defaults_without_a_config() {
+    let settings = Config::default().resolve(None, Section::default()).unwrap();
+    assert_eq!(settings.token, None);
+    assert_eq!(settings.year, 2021);
+    assert_eq!(settings.inputs, PathBuf::from("inputs"));
+    assert_eq!(settings.format, Format::Text);
+    assert_eq!(settings.color, Color::Auto);
+}
+
+#[test]
+fn profiles_override_the_top_level_settings() {
+    let config = Config::parse(CONFIG, "aoc.toml").unwrap();
+
+    let main = config.resolve(None, Section::default()).unwrap();
+    assert_eq!(main.token.as_deref(), Some("main-session"));
+    assert_eq!(main.year, 2020);
+
+    let alice = config.resolve(Some("alice"), Section::default()).unwrap();
+    assert_eq!(alice.token.as_deref(), Some("alice-session"));
+    assert_eq!(alice.year, 2021);
+    assert_eq!(alice.format, Format::Json);
+    assert_eq!(alice.color, Color::Never);
+    assert_eq!(alice.inputs, PathBuf::from("inputs/alice"));
+}
+
+#[test]
+fn profiles_never_borrow_the_top_level_token() {
+    let config = Config::parse(CONFIG, "aoc.toml").unwrap();
+    let bob = config.resolve(Some("bob"), Section::default()).unwrap();
+    assert_eq!(bob.token, None);
+    assert_eq!(bob.inputs, PathBuf::from("/data/bob"));
+}
+
+#[test]
+fn overrides_win() {
+    let config = Config::parse(CONFIG, "aoc.toml").unwrap();
+    let overrides = Section {
+        year: Some(2019),
+        format: Some(Format::Csv),
+        ..Section::default()
+    };
+    let alice = config.resolve(Some("alice"), overrides).unwrap();
+    assert_eq!(alice.year, 2019);
+    assert_eq!(alice.format, Format::Csv);
+    assert_eq!(alice.token.as_deref(), Some("alice-session"));
+}
+
+#[test]
+fn the_config_can_pick_a_default_profile() {
+    let config = Config::parse(&format!("profile = \"alice\"\n{}", CONFIG), "aoc.toml").unwrap();
+    let settings = config.resolve(None, Section::default()).unwrap();
+    assert_eq!(settings.profile.as_deref(), Some("alice"));
+    assert_eq!(settings.token.as_deref(), Some("alice-session"));
+}
+
+#[test]
+fn reports_unknown_profiles_and_bad_settings() {
+    let config = Config::parse(CONFIG, "aoc.toml").unwrap();
+    let err = config
+        .resolve(Some("carol"), Section::default())
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "No profile 'carol'. Available profiles: alice, bob"
+    );
+
+    let err = Config::parse("[profiles.alice]\ncolor = \"pink\"\n", "aoc.toml").unwrap_err();
+    assert!(matches!(err, ConfigError::Invalid { ref key, .. } if key == "profiles.alice.color"));
+
+    let err = Config::parse("tokn = \"x\"\n", "aoc.toml").unwrap_err();
+    assert_eq!(err.to_string(), "Invalid tokn in aoc.toml: unknown setting");
+}
+
+#[test]
+fn profiles_keep_their_inputs_apart_under_a_top_level_directory() {
+    let config = Config::parse(&format!("inputs = \"/shared\"\n{}", CONFIG), "aoc.toml").unwrap();
+
+    let main = config.resolve(None, Section::default()).unwrap();
+    assert_eq!(main.inputs, PathBuf::from("/shared"));
+    let alice = config.resolve(Some("alice"), Section::default()).unwrap();
+    assert_eq!(alice.inputs, PathBuf::from("/shared/alice"));
+    // A profile's own directory is used as it is.
+    let bob = config.resolve(Some("bob"), Section::default()).unwrap();
+    assert_eq!(bob.inputs, PathBuf::from("/data/bob"));
+}
+
+#[test]
+fn profiles_keep_their_inputs_apart_under_aoc_inputs() {
+    std::env::set_var("AOC_INPUTS", "/env");
+    let main = Section::from_env(None).unwrap();
+    let alice = Section::from_env(Some("alice")).unwrap();
+    std::env::remove_var("AOC_INPUTS");
+
+    assert_eq!(main.inputs, Some(PathBuf::from("/env")));
+    assert_eq!(alice.inputs, Some(PathBuf::from("/env/alice")));
+}
+
+#[test]
+fn base_url_comes_from_the_profile_then_the_top_level() {
+    let config = Config::parse(
+        "base_url = \"http://mirror\"\n[profiles.alice]\nbase_url = \"http://alice\"\n[profiles.bob]\n",
+        "aoc.toml",
+    )
+    .unwrap();
+    let url = |profile, overrides| config.resolve(profile, overrides).unwrap().base_url;
+    assert_eq!(url(Some("alice"), Section::default()), "http://alice");
+    assert_eq!(url(Some("bob"), Section::default()), "http://mirror");
+
+    let env = Section {
+        base_url: Some("http://localhost:8080".to_string()),
+        ..Section::default()
+    };
+    assert_eq!(url(Some("alice"), env), "http://localhost:8080");
+    assert_eq!(
+        Config::default()
+            .resolve(None, Section::default())
+            .unwrap()
+            .base_url,
+        "https://adventofcode.com"
+    );
+}
@@ -0,0 +1,244 @@
+mod common;
+
+use common::TempDir;
+use hello_rust::download::{DownloadError, Downloader, Fetched, Http, ReqwestHttp, Response};
+use std::error::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const NOT_READY: &str = "Please don't repeatedly request this endpoint before it unlocks! \
+                         The calendar countdown is synchronized with the server time.";
+
+/// Serves canned answers on a local port and returns its base URL. Requests
+/// without the expected session cookie get a 400, like the real site.
+async fn mock_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let mut buffer = vec![0; 4096];
+                let read = socket.read(&mut buffer).await.unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]).to_lowercase();
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+                let (status, body) = if !request.contains("cookie: session=secret") {
+                    (
+                        "400 Bad Request",
+                        "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+                    )
+                } else {
+                    match path {
+                        "/2021/day/1/input" => ("200 OK", "199\n200\n208\n"),
+                        "/2021/day/1" => ("200 OK", "<article><h2>--- Day 1 ---</h2></article>"),
+                        "/2021/day/2/input" => ("404 Not Found", NOT_READY),
+                        "/2021/day/4/input" => ("429 Too Many Requests", "slow down"),
+                        "/2021/day/40/input" => ("404 Not Found", "404 Not Found"),
+                        _ => ("500 Internal Server Error", "oops"),
+                    }
+                };
+                let response = format!(
+                    "HTTP/1.1 {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+
+    format!("http://{}", address)
+}
+
+async fn downloader() -> Downloader<ReqwestHttp> {
+    downloader_with_session("secret").await
+}
+
+async fn downloader_with_session(session: &str) -> Downloader<ReqwestHttp> {
+    Downloader::new(ReqwestHttp::new(session).unwrap(), mock_server().await)
+        .with_interval(Duration::ZERO)
+        .with_backoff(Duration::ZERO, 2)
+}
+
+#[tokio::test]
+async fn downloads_input() {
+    let fetched = downloader().await.download(2021, 1).await.unwrap();
+    assert_eq!(fetched, Fetched::Input("199\n200\n208\n".to_string()));
+}
+
+#[tokio::test]
+async fn fetches_puzzle_page() {
+    let fetched = downloader().await.fetch_puzzle(2021, 1).await.unwrap();
+    assert_eq!(
+        fetched,
+        Fetched::Input("<article><h2>--- Day 1 ---</h2></article>".to_string())
+    );
+}
+
+#[tokio::test]
+async fn reports_locked_puzzle_as_not_ready() {
+    let fetched = downloader().await.download(2021, 2).await.unwrap();
+    assert_eq!(fetched, Fetched::NotReady);
+}
+
+#[tokio::test]
+async fn fails_on_server_error() {
+    let result = downloader().await.download(2021, 3).await;
+    assert!(matches!(result, Err(DownloadError::Status(500))));
+}
+
+#[tokio::test]
+async fn detects_logged_out_page() {
+    let result = downloader_with_session("expired")
+        .await
+        .download(2021, 1)
+        .await;
+    assert!(matches!(result, Err(DownloadError::LoggedOut)));
+}
+
+#[tokio::test]
+async fn reports_missing_puzzle() {
+    let result = downloader().await.download(2021, 40).await;
+    assert!(matches!(result, Err(DownloadError::NotFound)));
+}
+
+#[tokio::test]
+async fn gives_up_when_throttled() {
+    let result = downloader().await.download(2021, 4).await;
+    assert!(matches!(result, Err(DownloadError::Throttled)));
+}
+
+#[tokio::test]
+async fn saves_input_to_file() {
+    let dir = TempDir::new("download-saves");
+    let path = dir.join("01");
+    let fetched = downloader()
+        .await
+        .download_to(2021, 1, &path)
+        .await
+        .unwrap();
+    assert_eq!(fetched, Fetched::Input("199\n200\n208\n".to_string()));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "199\n200\n208\n");
+}
+
+#[tokio::test]
+async fn writes_nothing_on_failure() {
+    let dir = TempDir::new("download-failure");
+    let path = dir.join("01");
+    for day in [2, 3] {
+        let _ = downloader().await.download_to(2021, day, &path).await;
+        assert!(!path.exists());
+    }
+}
+
+#[tokio::test]
+async fn refuses_to_overwrite_input() {
+    let dir = TempDir::new("download-existing");
+    let path = dir.join("01");
+    std::fs::write(&path, "mine").unwrap();
+    let result = downloader().await.download_to(2021, 1, &path).await;
+    assert!(matches!(result, Err(DownloadError::InputExists(_))));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "mine");
+}
+
+#[tokio::test]
+async fn fails_when_server_is_unreachable() {
+    let http = ReqwestHttp::new("secret").unwrap();
+    let result = Downloader::new(http, "http://127.0.0.1:1")
+        .with_backoff(Duration::ZERO, 0)
+        .download(2021, 1)
+        .await;
+    assert!(matches!(result, Err(DownloadError::Http(_))));
+}
+
+struct Fake;
+
+impl Http for Fake {
+    async fn get(&self, url: &str) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        Ok(Response {
+            status: 200,
+            body: url.to_string(),
+        })
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        _form: &[(&str, &str)],
+    ) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        self.get(url).await
+    }
+}
+
+#[tokio::test]
+async fn requests_year_and_day_under_base_url() {
+    let fetched = Downloader::new(Fake, "http://example.test/")
+        .download(2015, 7)
+        .await
+        .unwrap();
+    assert_eq!(
+        fetched,
+        Fetched::Input("http://example.test/2015/day/7/input".to_string())
+    );
+}
+
+/// Fails with a 503 until it has been asked `failures` times.
+struct Flaky {
+    failures: usize,
+    calls: AtomicUsize,
+}
+
+impl Http for Flaky {
+    async fn get(&self, _url: &str) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        let status = if call < self.failures { 503 } else { 200 };
+        Ok(Response {
+            status,
+            body: "input".to_string(),
+        })
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        _form: &[(&str, &str)],
+    ) -> Result<Response, Box<dyn Error + Send + Sync>> {
+        self.get(url).await
+    }
+}
+
+#[tokio::test]
+async fn retries_with_backoff() {
+    let flaky = Flaky {
+        failures: 2,
+        calls: AtomicUsize::new(0),
+    };
+    let start = Instant::now();
+    let fetched = Downloader::new(flaky, "http://example.test")
+        .with_interval(Duration::ZERO)
+        .with_backoff(Duration::from_millis(20), 3)
+        .download(2021, 1)
+        .await
+        .unwrap();
+
+    assert_eq!(fetched, Fetched::Input("input".to_string()));
+    // Backs off 20ms, then 40ms.
+    assert!(start.elapsed() >= Duration::from_millis(60));
+}
+
+#[tokio::test]
+async fn spaces_out_requests() {
+    let downloader = Downloader::new(Fake, "http://example.test")
+        .with_interval(Duration::from_millis(50))
+        .with_backoff(Duration::ZERO, 0);
+    let start = Instant::now();
+    for day in 1..=3 {
+        downloader.download(2021, day).await.unwrap();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use common::TempDir;
+use hello_rust::examples::{self, Expectation};
+use hello_rust::solutions;
+use std::path::Path;
+
+const PAGE: &str = include_str!("fixtures/2021-01.html");
+
+#[test]
+fn extracts_blocks_and_stated_answers() {
+    let extracted = examples::extract(1, PAGE);
+
+    let names: Vec<&str> = extracted
+        .examples
+        .iter()
+        .map(|example| example.name.as_str())
+        .collect();
+    assert_eq!(names, ["01-1", "01-2"]);
+    assert_eq!(
+        extracted.examples[0].text,
+        "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n"
+    );
+
+    let expectation = |part, answer: &str| Expectation {
+        example: "01-1".to_string(),
+        part,
+        answer: answer.to_string(),
+    };
+    assert_eq!(
+        extracted.expectations,
+        [expectation(1, "7"), expectation(2, "5")]
+    );
+}
+
+#[test]
+fn finds_nothing_without_code_blocks() {
+    let extracted = examples::extract(3, "<article><p>No <code><em>42</em></code></p></article>");
+    assert!(extracted.examples.is_empty());
+    assert!(extracted.expectations.is_empty());
+}
+
+#[test]
+fn saves_extracted_examples_and_their_answers() {
+    let dir = TempDir::new("examples");
+
+    let extracted = examples::extract_into(&dir, 1, PAGE).unwrap();
+    assert_eq!(extracted.examples.len(), 2);
+    assert_eq!(
+        std::fs::read_to_string(dir.join("01-1")).unwrap(),
+        extracted.examples[0].text
+    );
+    assert_eq!(examples::load_table(&dir).unwrap(), extracted.expectations);
+}
+
+#[test]
+fn pages_without_examples_are_an_error() {
+    let scratch = TempDir::new("no-examples");
+    let dir = scratch.join("examples");
+    let result = examples::extract_into(&dir, 3, "<article><p>Nothing here</p></article>");
+    assert_eq!(
+        result.err().map(|err| err.to_string()).as_deref(),
+        Some("No examples found for day 03")
+    );
+    assert!(!dir.exists());
+}
+
+/// Runs every solver against the examples recorded under `puzzles/examples/`.
+#[test]
+fn solvers_pass_their_examples() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("puzzles/examples");
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for day in solutions::all() {
+        match examples::check_day(day, root.join(day.year.to_string())) {
+            Ok(count) => checked += count,
+            Err(errors) => failures.extend(
+                errors
+                    .into_iter()
+                    .map(|err| format!("{} {}", day.year, err)),
+            ),
+        }
+    }
+
+    assert!(checked > 0, "no examples recorded");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
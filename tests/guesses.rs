@@ -0,0 +1,107 @@
+mod common;
+
+use common::TempDir;
+use hello_rust::guesses::{self, check, Bounds, Conflict, Guess};
+use hello_rust::submit::Verdict;
+use std::time::Duration;
+
+fn guess(answer: &str, verdict: Verdict) -> Guess {
+    Guess {
+        answer: answer.to_string(),
+        verdict,
+    }
+}
+
+fn history() -> Vec<Guess> {
+    vec![
+        guess("100", Verdict::TooLow),
+        guess("900", Verdict::TooHigh),
+        guess("150", Verdict::TooLow),
+        guess("700", Verdict::TooHigh),
+        guess("400", Verdict::Wrong),
+    ]
+}
+
+#[test]
+fn narrows_bounds_to_tightest_guesses() {
+    assert_eq!(
+        Bounds::from_guesses(&history()),
+        Bounds {
+            above: Some(150),
+            below: Some(700)
+        }
+    );
+}
+
+#[test]
+fn flags_answers_outside_bounds() {
+    let history = history();
+    assert_eq!(
+        check(&history, "150"),
+        Some(Conflict::Rejected(Verdict::TooLow))
+    );
+    assert_eq!(check(&history, "120"), Some(Conflict::NotAbove(150)));
+    assert_eq!(check(&history, "800"), Some(Conflict::NotBelow(700)));
+    assert_eq!(
+        check(&history, "400"),
+        Some(Conflict::Rejected(Verdict::Wrong))
+    );
+    assert_eq!(check(&history, "151"), None);
+    assert_eq!(check(&history, "699"), None);
+}
+
+#[test]
+fn accepts_the_correct_answer() {
+    let mut history = history();
+    history.push(guess("500", Verdict::Correct));
+    assert_eq!(check(&history, "500"), None);
+    // Inside the bounds, but not the answer that was accepted.
+    assert_eq!(
+        check(&history, "501"),
+        Some(Conflict::NotCorrect("500".to_string()))
+    );
+}
+
+#[test]
+fn only_rejections_apply_to_text_answers() {
+    let history = vec![
+        guess("ABCDEF", Verdict::Wrong),
+        guess("9", Verdict::TooHigh),
+    ];
+    assert_eq!(
+        check(&history, "ABCDEF"),
+        Some(Conflict::Rejected(Verdict::Wrong))
+    );
+    assert_eq!(check(&history, "ZZZZZZ"), None);
+    assert_eq!(check(&history, "10"), Some(Conflict::NotBelow(9)));
+}
+
+#[test]
+fn logs_judged_guesses() {
+    let dir = TempDir::new("guesses");
+    let log = dir.join("07-1");
+
+    guesses::record(&log, "10", &Verdict::TooLow).unwrap();
+    guesses::record(&log, "99", &Verdict::RateLimited(Duration::from_secs(30))).unwrap();
+    guesses::record(&log, "50", &Verdict::TooHigh).unwrap();
+
+    let logged = guesses::load(&log).unwrap();
+    assert_eq!(
+        logged,
+        vec![
+            Guess {
+                answer: "10".to_string(),
+                verdict: Verdict::TooLow
+            },
+            Guess {
+                answer: "50".to_string(),
+                verdict: Verdict::TooHigh
+            },
+        ]
+    );
+    assert_eq!(
+        guesses::previous(&logged, "50").map(|guess| &guess.verdict),
+        Some(&Verdict::TooHigh)
+    );
+    assert!(guesses::previous(&logged, "99").is_none());
+}
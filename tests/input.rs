@@ -0,0 +1,175 @@
+use hello_rust::solutions::y2021::{day01, day04, day06, day09, day10, day16, day25};
+use hello_rust::{ParseError, Solver};
+
+#[test]
+fn columns_come_from_the_tokens_position() {
+    let line = "move 12 to 7";
+    let err = ParseError::at(line, &line[5..7], "a count");
+    assert_eq!(err.column, 6);
+    assert_eq!(err.text, line);
+    assert_eq!(err.line, 0);
+
+    // Counted in characters, not bytes.
+    let line = "é→ 42";
+    let err = ParseError::at(line, &line[line.len() - 2..], "a number");
+    assert_eq!(err.column, 4);
+}
+
+#[test]
+fn tokens_from_elsewhere_point_at_the_start_of_the_line() {
+    let other = String::from("42");
+    let err = ParseError::at("some line", &other, "a number");
+    assert_eq!(err.column, 1);
+}
+
+#[test]
+fn display_puts_a_caret_under_the_column() {
+    let line = "fold along q=5";
+    let err = ParseError::at(line, &line[11..], "x= or y=")
+        .on_line(3)
+        .in_file("input/2021/13");
+
+    assert_eq!(err.line, 3);
+    assert_eq!(err.file.as_deref(), Some("input/2021/13"));
+    assert_eq!(
+        err.to_string(),
+        "input/2021/13:3:12: expected x= or y=\n    fold along q=5\n               ^"
+    );
+}
+
+#[test]
+fn errors_without_a_file_refer_to_the_input() {
+    let err = ParseError::new("abc", "a number").on_line(1);
+    assert_eq!(
+        err.to_string(),
+        "<input>:1:1: expected a number\n    abc\n    ^"
+    );
+}
+
+#[test]
+fn end_of_input_has_no_caret() {
+    let err = ParseError::end_of_input("a blank line")
+        .on_line(7)
+        .in_file("example");
+    assert_eq!(
+        err.to_string(),
+        "example:7: expected a blank line, found end of input"
+    );
+}
+
+#[test]
+fn empty_lines_are_not_the_end_of_input() {
+    let err = day01::Problem
+        .read_str("1\n\n3\n")
+        .expect_err("the blank line should be rejected");
+    assert!(!err.end_of_input);
+    assert_eq!(
+        err.to_string(),
+        "<input>:2: expected a depth measurement, found an empty line"
+    );
+}
+
+fn grid_error(input: &str) -> ParseError {
+    day09::Problem
+        .read_str(input)
+        .expect_err("the grid should be rejected")
+}
+
+#[test]
+fn digit_grids_point_at_the_first_non_digit() {
+    let err = grid_error("123\n1x3\n");
+    assert_eq!((err.line, err.column), (2, 2));
+    assert_eq!(err.expected, "a digit");
+
+    let err = grid_error("123\n12é\n");
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.expected, "a digit");
+}
+
+#[test]
+fn digit_grids_point_past_the_width_of_uneven_rows() {
+    let err = grid_error("123\n1234\n");
+    assert_eq!((err.line, err.column), (2, 4));
+    assert_eq!(err.expected, "a row of 3 digits");
+
+    let err = grid_error("123\n1\n");
+    assert_eq!((err.line, err.column), (2, 2));
+
+    let err = grid_error("123\n12éé\n");
+    assert_eq!((err.line, err.column), (2, 4));
+}
+
+#[test]
+fn truncated_transmissions_end_early() {
+    for input in ["1\n", "\n\n", "D2FE2\n", "38006F452912\n"] {
+        let err = day16::Problem
+            .read_str(input)
+            .expect_err("the transmission should be rejected");
+        assert_eq!(err.line, 1, "{:?}", input);
+        assert!(err.end_of_input, "{:?}", input);
+        assert!(err.expected.ends_with("bits of a packet"), "{:?}", input);
+    }
+}
+
+#[test]
+fn sea_floor_rows_are_measured_in_characters() {
+    let err = day25::Problem
+        .read_str("v\né\n")
+        .expect_err("the cell should be rejected");
+    assert_eq!((err.line, err.column), (2, 1));
+    assert_eq!(err.expected, "one of 'v', '>' or '.'");
+
+    let err = day25::Problem
+        .read_str("v.\né.>\n")
+        .expect_err("the row should be rejected");
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.expected, "a row of 2 cells");
+}
+
+#[test]
+fn navigation_lines_only_hold_brackets() {
+    let err = day10::Problem
+        .read_str("()\n[(x]\n")
+        .expect_err("the line should be rejected");
+    assert_eq!((err.line, err.column), (2, 3));
+    assert_eq!(err.expected, "a bracket");
+}
+
+#[test]
+fn bingo_boards_are_full_grids() {
+    let board = "1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25\n";
+    let input = day04::Problem
+        .read_str(&format!("1,2\n\n{}\n\n", board))
+        .expect("trailing blank lines should be ignored");
+    assert_eq!(input.bingo_cards.len(), 1);
+
+    let err = day04::Problem
+        .read_str("1,2\n")
+        .expect_err("input without boards should be rejected");
+    assert_eq!(err.line, 2);
+    assert_eq!(err.expected, "a bingo board");
+
+    let err = day04::Problem
+        .read_str("1,2\n\n1 2\n3\n")
+        .expect_err("the ragged board should be rejected");
+    assert_eq!((err.line, err.column), (3, 4));
+    assert_eq!(err.expected, "a row of 5 card numbers");
+
+    let err = day04::Problem
+        .read_str(&format!(
+            "1,2\n\n{}\n1 2 3 4 5\n",
+            board.replacen("21 22 23 24 25\n", "", 1)
+        ))
+        .expect_err("the short board should be rejected");
+    assert_eq!(err.line, 7);
+    assert_eq!(err.expected, "a board of 5 rows");
+}
+
+#[test]
+fn lanternfish_timers_stop_at_eight() {
+    let err = day06::Problem
+        .read_str("3,8,188\n")
+        .expect_err("the timer should be rejected");
+    assert_eq!((err.line, err.column), (1, 5));
+    assert_eq!(err.expected, "a lanternfish timer from 0 to 8");
+}
@@ -0,0 +1,85 @@
+use hello_rust::solutions::memory::{self, CountingAllocator, Usage};
+use hello_rust::solutions::{PartReport, RawInput, Solver};
+use std::time::Duration;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn counts_allocations_and_the_peak_of_what_was_freed() {
+    memory::enable();
+
+    let (sum, usage) = memory::measure(|| {
+        let big = vec![1u8; 4096];
+        let sum: usize = big.iter().map(|&b| b as usize).sum();
+        drop(big);
+        let small: Vec<u64> = Vec::with_capacity(16);
+        sum + small.capacity()
+    });
+
+    assert_eq!(sum, 4112);
+    let usage = usage.unwrap();
+    assert_eq!(usage.allocations, 2);
+    assert_eq!(usage.bytes, 4096 + 128);
+    assert_eq!(usage.peak, 4096);
+}
+
+#[test]
+fn an_inner_measurement_keeps_the_outer_peak() {
+    memory::enable();
+
+    let (inner, outer) = memory::measure(|| {
+        let kept = vec![0u8; 1000];
+        let (_, inner) = memory::measure(|| vec![0u8; 10]);
+        drop(kept);
+        inner
+    });
+
+    assert_eq!(inner.unwrap().peak, 10);
+    assert_eq!(outer.unwrap().peak, 1010);
+}
+
+#[test]
+fn reports_parse_and_parts_separately() {
+    memory::enable();
+
+    let input = RawInput {
+        name: "example".to_string(),
+        text: "199\n200\n208\n210\n".to_string(),
+    };
+    let report = hello_rust::solutions::y2021::day01::Problem
+        .solve(&input, 3)
+        .unwrap();
+
+    assert!(report.parse_memory.unwrap().allocations > 0);
+    assert!(report.parts.iter().all(|part| part.memory.is_some()));
+    assert!(report.to_string().starts_with("Parsing: "));
+}
+
+#[test]
+fn shows_usage_next_to_the_time() {
+    let part = PartReport {
+        part: 1,
+        answer: Ok("7".to_string()),
+        elapsed: Duration::from_micros(1500),
+        memory: Some(Usage {
+            allocations: 3,
+            bytes: 3 * 1024 * 1024,
+            peak: 512,
+        }),
+    };
+    assert_eq!(
+        part.to_string(),
+        "Solution to part 1: 7 (1500 μs, 3 allocations, 3.0 MiB allocated, 512 B peak)"
+    );
+}
+
+#[test]
+fn a_single_allocation_is_singular() {
+    let usage = Usage {
+        allocations: 1,
+        bytes: 16,
+        peak: 16,
+    };
+    assert_eq!(usage.to_string(), "1 allocation, 16 B allocated, 16 B peak");
+}
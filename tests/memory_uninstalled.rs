@@ -0,0 +1,14 @@
+//! Without `CountingAllocator` as the global allocator, enabling memory
+//! figures mustn't make up empty ones.
+
+use hello_rust::solutions::memory;
+
+#[test]
+fn never_counts_without_the_counting_allocator() {
+    memory::enable();
+    assert!(!memory::is_enabled());
+
+    let (bytes, usage) = memory::measure(|| vec![0u8; 64]);
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(usage, None);
+}
@@ -0,0 +1,42 @@
+use hello_rust::output::{csv_cell, input_hash, json_records, SOLVE_COLUMNS};
+use serde_json::{json, Value};
+
+#[test]
+fn plain_cells_are_left_alone() {
+    assert_eq!(csv_cell(&Value::Null), "");
+    assert_eq!(csv_cell(&json!(2021)), "2021");
+    assert_eq!(csv_cell(&json!("1655")), "1655");
+    assert_eq!(csv_cell(&json!(true)), "true");
+}
+
+#[test]
+fn cells_with_separators_are_quoted() {
+    assert_eq!(csv_cell(&json!("3,4")), "\"3,4\"");
+    assert_eq!(csv_cell(&json!("line\nbreak")), "\"line\nbreak\"");
+    assert_eq!(csv_cell(&json!("line\rbreak")), "\"line\rbreak\"");
+    assert_eq!(
+        csv_cell(&json!("expected \"x=\" here")),
+        "\"expected \"\"x=\"\" here\""
+    );
+}
+
+#[test]
+fn json_keys_follow_the_csv_columns() {
+    let row: Vec<Value> = SOLVE_COLUMNS.iter().map(|_| Value::Null).collect();
+    let records = json_records(&SOLVE_COLUMNS, &[row]);
+    let keys: Vec<&str> = records[0]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(keys, SOLVE_COLUMNS);
+}
+
+#[test]
+fn hashes_inputs_with_fnv_1a() {
+    assert_eq!(input_hash(""), "cbf29ce484222325");
+    assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    assert_eq!(input_hash("foobar"), "85944171f73967e8");
+    assert_ne!(input_hash("199\n200\n"), input_hash("199\n200"));
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::{Site, TempDir};
+use hello_rust::download::{DownloadError, Downloader};
+use hello_rust::puzzle::{self, Node, Style};
+
+const PAGE: &str = include_str!("fixtures/2021-01.html");
+
+#[test]
+fn finds_one_article_per_part() {
+    let articles = puzzle::articles(PAGE);
+    assert_eq!(articles.len(), 2);
+    assert!(articles[0].text().starts_with("--- Day 1: Sonar Sweep ---"));
+    assert!(articles[1].text().starts_with("--- Part Two ---"));
+}
+
+#[test]
+fn reads_the_title_from_the_first_heading() {
+    let articles = puzzle::articles(PAGE);
+    assert_eq!(puzzle::title(&articles).as_deref(), Some("Sonar Sweep"));
+    assert_eq!(puzzle::title(&articles[1..]), None);
+}
+
+#[test]
+fn skips_comments_and_scripts() {
+    let root = puzzle::parse(PAGE);
+    let text = root.text();
+    assert!(!text.contains("Funny seeing you here"));
+    assert!(!text.contains("window.onload"));
+}
+
+#[test]
+fn decodes_entities_and_keeps_links() {
+    let root = puzzle::parse(
+        r#"<p>a &lt;b&gt; &amp; &#39;c&#x27; &unknown; <a href="/x?a=1&amp;b=2">d</a></p>"#,
+    );
+    assert_eq!(root.text(), "a <b> & 'c' &unknown; d");
+    assert_eq!(
+        root.find_all("a")[0],
+        &Node::Element {
+            name: "a".to_string(),
+            href: Some("/x?a=1&b=2".to_string()),
+            children: vec![Node::Text("d".to_string())],
+        }
+    );
+}
+
+#[test]
+fn renders_markdown() {
+    let markdown = puzzle::render(&puzzle::articles(PAGE), Style::Markdown);
+    assert!(markdown.starts_with("## --- Day 1: Sonar Sweep ---\n\n"));
+    assert!(markdown.contains("help with the **sonar sweep**."));
+    assert!(markdown.contains("```\n199\n200\n208\n"));
+    assert!(markdown.contains("- Depths are compared with `>` & nothing else.\n"));
+    assert!(markdown.contains("there are **`7`** measurements"));
+    assert!(markdown.contains(
+        "[three-measurement sliding window](https://en.wikipedia.org/wiki/Moving_average)"
+    ));
+    assert!(!markdown.contains("Your puzzle answer was"));
+}
+
+#[test]
+fn renders_plain_text() {
+    let text = puzzle::render(&puzzle::articles(PAGE), Style::Plain);
+    assert!(text.contains("help with the sonar sweep.\n\n"));
+    assert!(text.contains("\n    199\n    200\n"));
+    assert!(text.contains("\n  - The first measurement has no previous measurement.\n"));
+    assert!(!text.contains('\x1b'));
+    assert!(!text.contains('<'));
+
+    let bold = puzzle::render(&puzzle::articles(PAGE), Style::Text);
+    assert!(bold.contains("help with the \x1b[1msonar sweep\x1b[0m."));
+}
+
+#[tokio::test]
+async fn fetches_and_caches_pages() {
+    let site = Site::new(200, PAGE);
+    let dir = TempDir::new("puzzles-fetch");
+    let path = dir.join("01.html");
+
+    let html = puzzle::cached_page(&path, 2021, 1, false, || Ok(site.downloader())).await;
+    assert_eq!(html.unwrap().as_deref(), Some(PAGE));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), PAGE);
+
+    let html = puzzle::cached_page(&path, 2021, 1, false, || Ok(site.downloader())).await;
+    assert_eq!(html.unwrap().as_deref(), Some(PAGE));
+    assert_eq!(site.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn reads_cached_pages_without_a_session() {
+    let dir = TempDir::new("puzzles-cached");
+    let path = dir.join("01.html");
+    std::fs::write(&path, "<article>cached</article>").unwrap();
+
+    let no_session = || Err::<Downloader<&Site>, _>(DownloadError::MissingToken);
+    let html = puzzle::cached_page(&path, 2021, 1, false, no_session).await;
+    assert_eq!(html.unwrap().as_deref(), Some("<article>cached</article>"));
+
+    let html = puzzle::cached_page(&path, 2021, 1, true, no_session).await;
+    assert!(matches!(html, Err(DownloadError::MissingToken)));
+}
+
+#[tokio::test]
+async fn refreshing_replaces_the_cached_page() {
+    let site = Site::new(200, PAGE);
+    let dir = TempDir::new("puzzles-refresh");
+    let path = dir.join("01.html");
+    std::fs::write(&path, "<article>part one only</article>").unwrap();
+
+    let html = puzzle::cached_page(&path, 2021, 1, true, || Ok(site.downloader())).await;
+    assert_eq!(html.unwrap().as_deref(), Some(PAGE));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), PAGE);
+}
+
+#[tokio::test]
+async fn caches_nothing_before_the_puzzle_unlocks() {
+    let site = Site::new(
+        404,
+        "Please don't repeatedly request this endpoint before it unlocks!",
+    );
+    let dir = TempDir::new("puzzles-locked");
+    let path = dir.join("01.html");
+
+    let html = puzzle::cached_page(&path, 2021, 1, false, || Ok(site.downloader())).await;
+    assert_eq!(html.unwrap(), None);
+    assert!(!path.exists());
+}
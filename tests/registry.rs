@@ -0,0 +1,91 @@
+mod common;
+#[path = "../build/numbered.rs"]
+mod numbered;
+
+use common::TempDir;
+use hello_rust::solutions::{self, y2021::day01};
+use hello_rust::Solver;
+use numbered::numbered_entries;
+
+#[test]
+fn finds_registered_days() {
+    let day = solutions::find(2021, 1).unwrap();
+    assert_eq!((day.year, day.number), (2021, 1));
+    assert_eq!(day.title, "Sonar Sweep");
+
+    let days: Vec<(isize, isize)> = solutions::all()
+        .iter()
+        .map(|day| (day.year, day.number))
+        .collect();
+    let mut sorted = days.clone();
+    sorted.sort();
+    assert_eq!(days, sorted);
+    assert!(solutions::year(2021).all(|day| day.year == 2021));
+}
+
+#[test]
+fn unknown_days_list_the_available_ones() {
+    let Err(err) = solutions::find(2021, 26) else {
+        panic!("2021 has no day 26");
+    };
+    let available: Vec<isize> = solutions::year(2021).map(|day| day.number).collect();
+    assert_eq!(err.available, available);
+
+    let listed: Vec<String> = available.iter().map(|n| n.to_string()).collect();
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Day 26 of 2021 is not implemented. Available days: {}",
+            listed.join(", ")
+        )
+    );
+}
+
+#[test]
+fn years_without_solutions_say_so() {
+    let Err(err) = solutions::find(1999, 1) else {
+        panic!("1999 has no solutions");
+    };
+    assert!(err.available.is_empty());
+    assert_eq!(err.to_string(), "There are no solutions for 1999 yet");
+}
+
+#[test]
+fn solves_input_given_as_a_string() {
+    let example = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+    let input = day01::Problem.read_str(example).unwrap();
+    assert_eq!(input.len(), 10);
+
+    let (first, second) = day01::Problem.solve_str(example).unwrap();
+    assert_eq!(first.unwrap(), 7);
+    assert_eq!(second.unwrap(), 5);
+
+    let err = day01::Problem.read_str("199\nabc\n").unwrap_err();
+    assert_eq!(err.line, 2);
+}
+
+#[test]
+fn only_registers_names_with_the_exact_digit_count() {
+    let dir = TempDir::new("registry");
+    let names = [
+        "day01.rs",
+        "day25.rs",
+        "day7.rs",
+        "day100.rs",
+        "day+1.rs",
+        "day0a.rs",
+        "day02.txt",
+        "days03.rs",
+        "mod.rs",
+    ];
+    for name in names {
+        std::fs::write(dir.join(name), "").unwrap();
+    }
+
+    let mut entries = numbered_entries(&dir, "day", ".rs", 2);
+    entries.sort();
+    assert_eq!(
+        entries,
+        [(1, "day01.rs".to_string()), (25, "day25.rs".to_string())]
+    );
+}
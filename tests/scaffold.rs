@@ -0,0 +1,68 @@
+use hello_rust::scaffold::{self, Preset};
+
+#[test]
+fn parses_every_listed_preset() {
+    for name in scaffold::PRESETS {
+        assert!(name.parse::<Preset>().is_ok(), "{}", name);
+    }
+    assert!("lines".parse::<Preset>().is_err());
+}
+
+#[test]
+fn generates_the_preset_parser_and_an_example_test() {
+    let source = scaffold::generate(2021, 9, Preset::GridU8, "Smoke Basin");
+    assert!(source.contains("pub const TITLE: &str = \"Smoke Basin\";"));
+    assert!(source.contains("type Input = Array2<u8>;"));
+    assert!(source.contains("parse_digit_grid(file_reader)"));
+    assert!(source.contains("solutions::find(2021, 9)"));
+    assert!(source.contains("/puzzles/examples/2021"));
+}
+
+/// The parameters a generated `impl Solver` method takes, other than `self`.
+fn parameter_names(method: &syn::ImplItemMethod) -> Vec<String> {
+    method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            syn::FnArg::Typed(arg) => match &*arg.pat {
+                syn::Pat::Ident(ident) => Some(ident.ident.to_string()),
+                _ => None,
+            },
+            syn::FnArg::Receiver(_) => None,
+        })
+        .collect()
+}
+
+#[test]
+fn every_preset_generates_valid_rust() {
+    for name in scaffold::PRESETS {
+        let source = scaffold::generate(2021, 9, name.parse().unwrap(), "Smoke Basin");
+        let file = syn::parse_file(&source)
+            .unwrap_or_else(|err| panic!("{} generated invalid Rust: {}\n{}", name, err, source));
+
+        let methods: Vec<_> = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Impl(solver) => Some(&solver.items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                syn::ImplItem::Method(method) => Some(method),
+                _ => None,
+            })
+            .map(|method| (method.sig.ident.to_string(), parameter_names(method)))
+            .collect();
+        // Unimplemented parts don't use their input yet, so it mustn't warn.
+        for part in ["solve_first", "solve_second"] {
+            assert!(
+                methods.contains(&(part.to_string(), vec!["_input".to_string()])),
+                "{}: {:?}",
+                name,
+                methods
+            );
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use hello_rust::solutions::{
+    cancelled, catch, checkpoint, error_chain, parse_timeout, InputError, ParseError, PartReport,
+    Puzzle, RawInput, SolveOptions, Solver, SolverError,
+};
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+fn report(answer: Result<String, SolverError>) -> String {
+    PartReport {
+        part: 2,
+        answer,
+        elapsed: Duration::from_micros(1500),
+        memory: None,
+    }
+    .to_string()
+}
+
+#[test]
+fn renders_each_kind_of_error_distinctly() {
+    assert_eq!(
+        report(Ok("42".to_string())),
+        "Solution to part 2: 42 (1500 μs)"
+    );
+    assert_eq!(
+        report(Err(SolverError::NotImplemented)),
+        "Part 2 is not implemented"
+    );
+    assert_eq!(
+        report(Err(SolverError::NoSolution("no path".to_string()))),
+        "Part 2 found no solution: no path (1500 μs)"
+    );
+    assert_eq!(
+        report(Err(SolverError::invalid_input("no start"))),
+        "Part 2 rejected the input: no start"
+    );
+    assert_eq!(
+        report(Err(SolverError::Timeout(Duration::from_secs(5)))),
+        "Part 2 timed out after 5000 ms"
+    );
+}
+
+#[test]
+fn chains_the_cause() {
+    let cause = u8::try_from(300).unwrap_err();
+    let err = SolverError::overflow("risk level").caused_by(cause);
+    assert_eq!(
+        error_chain(&err),
+        "overflow: risk level: out of range integral type conversion attempted"
+    );
+    assert_eq!(
+        report(Err(err)),
+        "Part 2 overflowed: risk level: out of range integral type conversion attempted"
+    );
+}
+
+#[test]
+fn gives_each_kind_its_own_exit_code() {
+    let errors = [
+        SolverError::NotImplemented,
+        SolverError::NoSolution(String::new()),
+        SolverError::invalid_input(""),
+        SolverError::overflow(""),
+        SolverError::Timeout(Duration::ZERO),
+        SolverError::Panicked(catch(|| panic!("boom")).unwrap_err()),
+    ];
+    let codes: HashSet<i32> = errors.iter().map(SolverError::exit_code).collect();
+    assert_eq!(codes.len(), errors.len());
+    assert!(codes.iter().all(|&code| code > 1));
+}
+
+/// Panics on input "parse", and in part 1 for any other input.
+struct Fragile;
+
+impl Solver for Fragile {
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, mut file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let mut text = String::new();
+        file_reader.read_to_string(&mut text).unwrap();
+        assert_ne!(text, "parse", "bad input");
+        Ok(text)
+    }
+
+    fn solve_first(&self, input: &Self::Input) -> Result<Self::Output1, SolverError> {
+        let numbers: Vec<usize> = Vec::new();
+        Ok(numbers[input.len()])
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
+        Ok(input.len())
+    }
+}
+
+fn raw(text: &str) -> RawInput {
+    RawInput {
+        name: "fragile".to_string(),
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn a_panicking_part_does_not_stop_the_other() {
+    let report = Solver::solve(&Fragile, &raw("abc"), 3).unwrap();
+
+    let panic = match &report.parts[0].answer {
+        Err(SolverError::Panicked(panic)) => panic,
+        other => panic!("expected a panic, got {:?}", other),
+    };
+    assert!(panic.message.contains("index out of bounds"));
+    assert!(panic.location.as_ref().unwrap().contains("tests/solver.rs"));
+    assert_eq!(report.parts[1].answer.as_deref().ok(), Some("3"));
+}
+
+#[test]
+fn a_panicking_parser_is_an_input_error() {
+    match Solver::solve(&Fragile, &raw("parse"), 3) {
+        Err(InputError::Panicked(name, panic)) => {
+            assert_eq!(name, "fragile");
+            assert!(panic.message.contains("bad input"));
+        }
+        other => panic!("expected a parse panic, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn catch_returns_the_value_when_nothing_panics() {
+    assert_eq!(catch(|| 7), Ok(7));
+    let panic = catch(|| -> usize { panic!("boom") }).unwrap_err();
+    assert_eq!(panic.message, "boom");
+    assert!(panic.to_string().starts_with("panicked at "));
+}
+
+static SPINNER_STOPPED: AtomicBool = AtomicBool::new(false);
+
+/// Part 1 spins until it's cancelled, part 2 answers straight away.
+struct Spinner;
+
+impl Solver for Spinner {
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn read_input(&self, mut file_reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let mut text = String::new();
+        file_reader.read_to_string(&mut text).unwrap();
+        Ok(text)
+    }
+
+    fn solve_first(&self, _: &Self::Input) -> Result<Self::Output1, SolverError> {
+        let stopped = loop {
+            if let Err(err) = checkpoint() {
+                break err;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        };
+        SPINNER_STOPPED.store(true, Ordering::SeqCst);
+        Err(stopped)
+    }
+
+    fn solve_second(&self, input: &Self::Input) -> Result<Self::Output2, SolverError> {
+        Ok(input.len())
+    }
+}
+
+#[test]
+fn a_part_over_its_time_limit_is_cancelled_and_the_other_still_runs() {
+    let options = SolveOptions {
+        timeout: Some(Duration::from_millis(50)),
+        ..SolveOptions::parts(3)
+    };
+    let report = Puzzle::solve(&Spinner, &raw("abcd"), &options).unwrap();
+
+    match &report.parts[0].answer {
+        Err(SolverError::Timeout(limit)) => assert_eq!(*limit, Duration::from_millis(50)),
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+    assert!(SPINNER_STOPPED.load(Ordering::SeqCst));
+    assert_eq!(report.parts[1].answer.as_deref().ok(), Some("4"));
+    assert_eq!(report.parts[0].to_string(), "Part 1 timed out after 50 ms");
+}
+
+#[test]
+fn without_a_time_limit_nothing_is_cancelled() {
+    assert!(!cancelled());
+    assert!(checkpoint().is_ok());
+
+    let report = Puzzle::solve(&Fragile, &raw("abc"), &SolveOptions::parts(2)).unwrap();
+    assert_eq!(report.parts[0].answer.as_deref().ok(), Some("3"));
+}
+
+static ARRIVED: AtomicUsize = AtomicUsize::new(0);
+
+/// Each part waits, for up to a few seconds, for the other to start too.
+struct Rendezvous;
+
+impl Rendezvous {
+    fn meet(&self) -> bool {
+        ARRIVED.fetch_add(1, Ordering::SeqCst);
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(5) {
+            if ARRIVED.load(Ordering::SeqCst) == 2 {
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        false
+    }
+}
+
+impl Solver for Rendezvous {
+    type Input = ();
+    type Output1 = bool;
+    type Output2 = bool;
+
+    fn read_input(&self, _: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok(())
+    }
+
+    fn solve_first(&self, _: &Self::Input) -> Result<Self::Output1, SolverError> {
+        Ok(self.meet())
+    }
+
+    fn solve_second(&self, _: &Self::Input) -> Result<Self::Output2, SolverError> {
+        Ok(self.meet())
+    }
+}
+
+#[test]
+fn parallel_parts_run_at_the_same_time() {
+    let options = SolveOptions {
+        parallel: true,
+        ..SolveOptions::parts(3)
+    };
+    let report = Puzzle::solve(&Rendezvous, &raw(""), &options).unwrap();
+
+    let numbers: Vec<_> = report.parts.iter().map(|part| part.part).collect();
+    assert_eq!(numbers, [1, 2]);
+    for part in &report.parts {
+        assert_eq!(part.answer.as_deref().ok(), Some("true"));
+    }
+}
+
+#[test]
+fn days_report_bad_inputs_as_typed_errors() {
+    use hello_rust::solutions::y2021::{day01, day03, day09, day10, day11, day18, day19, day24};
+
+    let (_, second) = day03::Problem.solve_str("1\n0\n1\n").unwrap();
+    assert!(matches!(second, Err(SolverError::InvalidInput { .. })));
+
+    let scanners = "--- scanner 0 ---\n0,0,0\n\n--- scanner 1 ---\n1,1,1\n";
+    let (first, _) = day19::Problem.solve_str(scanners).unwrap();
+    assert!(matches!(first, Err(SolverError::NoSolution(_))));
+
+    let (first, _) = day24::Problem.solve_str("inp w\nmod w 0\n").unwrap();
+    assert!(matches!(first, Err(SolverError::InvalidInput { .. })));
+    let (first, second) = day01::Problem.solve_str("").unwrap();
+    assert!(matches!(first, Err(SolverError::InvalidInput { .. })));
+    assert!(matches!(second, Err(SolverError::InvalidInput { .. })));
+
+    let (_, second) = day10::Problem.solve_str("()\n").unwrap();
+    assert!(matches!(second, Err(SolverError::NoSolution(_))));
+
+    let (first, _) = day18::Problem.solve_str("").unwrap();
+    assert!(matches!(first, Err(SolverError::InvalidInput { .. })));
+
+    let (first, second) = day09::Problem.solve_str("5\n").unwrap();
+    assert_eq!(first.ok(), Some(6));
+    assert!(matches!(second, Err(SolverError::NoSolution(_))));
+
+    assert!(day11::Problem.solve_str("5\n").unwrap().0.is_ok());
+}
+
+#[test]
+fn flashes_across_a_large_grid_do_not_overflow_the_stack() {
+    use hello_rust::solutions::y2021::day11;
+
+    let row = "9".repeat(150);
+    let grid = vec![row.as_str(); 150].join("\n");
+    let (_, second) = day11::Problem.solve_str(&grid).unwrap();
+    assert_eq!(second.ok(), Some(1));
+}
+
+#[test]
+fn integer_conversions_become_overflows() {
+    let err: SolverError = u8::try_from(300i32).unwrap_err().into();
+    assert_eq!(err.kind(), "overflow");
+    assert!(error_chain(&err).starts_with("overflow: integer conversion: "));
+}
+
+#[test]
+fn timeouts_take_a_unit_or_default_to_seconds() {
+    assert_eq!(parse_timeout("30"), Ok(Duration::from_secs(30)));
+    assert_eq!(parse_timeout(" 1.5s "), Ok(Duration::from_millis(1500)));
+    assert_eq!(parse_timeout("500ms"), Ok(Duration::from_millis(500)));
+    assert_eq!(parse_timeout("2m"), Ok(Duration::from_secs(120)));
+}
+
+#[test]
+fn invalid_timeouts_are_errors() {
+    assert_eq!(
+        parse_timeout("5h"),
+        Err("Invalid timeout '5h': unknown unit 'h'".to_string())
+    );
+    for text in ["", "0", "0s", "ms", "1.2.3s", "-5"] {
+        assert!(parse_timeout(text).is_err(), "accepted '{}'", text);
+    }
+    // Too long to be a `Duration`, rather than a panic.
+    assert!(parse_timeout("99999999999999999999999m").is_err());
+    assert!(parse_timeout(&"9".repeat(400)).is_err());
+}
@@ -0,0 +1,153 @@
+mod common;
+
+use common::{Site, TempDir};
+use hello_rust::download::DownloadError;
+use hello_rust::guesses::{self, Guess};
+use hello_rust::submit::{Submission, SubmitError, Verdict};
+use hello_rust::{answers, guesses::Conflict};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const CORRECT: &str = "<main><article><p>That's the right answer! You are one gold star closer \
+                       to saving your vacation.</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high. \
+                        Please wait one minute before trying again.</p></article></main>";
+const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low. \
+                       Please wait one minute before trying again.</p></article></main>";
+const WRONG: &str = "<main><article><p>That's not the right answer. If you're stuck, make sure \
+                     you're using the full input data.</p></article></main>";
+const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait \
+                          after submitting an answer before trying again.  You have 4m 35s left \
+                          to wait.</p></article></main>";
+const SOLVED: &str = "<main><article><p>You don't seem to be solving the right level.  Did you \
+                      already complete it?</p></article></main>";
+
+#[test]
+fn parses_verdicts() {
+    assert_eq!(Verdict::parse(CORRECT), Some(Verdict::Correct));
+    assert_eq!(Verdict::parse(TOO_HIGH), Some(Verdict::TooHigh));
+    assert_eq!(Verdict::parse(TOO_LOW), Some(Verdict::TooLow));
+    assert_eq!(Verdict::parse(WRONG), Some(Verdict::Wrong));
+    assert_eq!(
+        Verdict::parse(TOO_RECENT),
+        Some(Verdict::RateLimited(Duration::from_secs(275)))
+    );
+    assert_eq!(Verdict::parse(SOLVED), Some(Verdict::AlreadySolved));
+    assert_eq!(Verdict::parse("<html>maintenance</html>"), None);
+}
+
+#[test]
+fn parses_wait_without_minutes() {
+    let body = "You gave an answer too recently. You have 35s left to wait.";
+    assert_eq!(
+        Verdict::parse(body),
+        Some(Verdict::RateLimited(Duration::from_secs(35)))
+    );
+}
+
+#[tokio::test]
+async fn posts_level_and_answer() {
+    let site = Site::new(200, TOO_LOW);
+    let verdict = site.downloader().submit(2021, 7, 2, "1234").await.unwrap();
+    assert_eq!(verdict, Verdict::TooLow);
+
+    assert_eq!(
+        site.requests(),
+        vec![(
+            "http://example.test/2021/day/7/answer".to_string(),
+            Some(vec![
+                ("level".to_string(), "2".to_string()),
+                ("answer".to_string(), "1234".to_string())
+            ])
+        )]
+    );
+}
+
+#[tokio::test]
+async fn rejects_unknown_page() {
+    let site = Site::new(200, "<html>maintenance</html>");
+    let result = site.downloader().submit(2021, 7, 1, "1").await;
+    assert!(matches!(result, Err(DownloadError::UnexpectedResponse)));
+}
+
+#[tokio::test]
+async fn recognises_the_login_page() {
+    let site = Site::new(
+        200,
+        "<main><p>To play, please identify yourself. Please log in.</p></main>",
+    );
+    let result = site.downloader().submit(2021, 7, 1, "1").await;
+    assert!(matches!(result, Err(DownloadError::LoggedOut)));
+}
+
+/// A submission whose guesses log and answers file are in `dir`.
+fn submission(dir: &TempDir, part: u8, answer: &str) -> Submission {
+    Submission {
+        year: 2021,
+        day: 7,
+        part,
+        answer: answer.to_string(),
+        guesses: dir.join("guesses"),
+        answers: Some(dir.join("answers")),
+    }
+}
+
+fn answers_file(submission: &Submission) -> &PathBuf {
+    submission.answers.as_ref().unwrap()
+}
+
+#[tokio::test]
+async fn logs_the_verdict_of_a_submission() {
+    let site = Site::new(200, TOO_HIGH);
+    let dir = TempDir::new("submit-wrong");
+    let submission = submission(&dir, 1, "500");
+
+    let verdict = submission.send(&site.downloader()).await.unwrap();
+    assert_eq!(verdict, Verdict::TooHigh);
+    assert_eq!(
+        guesses::load(&submission.guesses).unwrap(),
+        [Guess {
+            answer: "500".to_string(),
+            verdict: Verdict::TooHigh
+        }]
+    );
+    assert_eq!(
+        answers::read(answers_file(&submission)).unwrap(),
+        [None, None]
+    );
+}
+
+#[tokio::test]
+async fn records_correct_answers_next_to_the_other_part() {
+    let site = Site::new(200, CORRECT);
+    let dir = TempDir::new("submit-correct");
+    let submission = submission(&dir, 2, "168");
+    answers::write(answers_file(&submission), &[Some("37".to_string()), None]).unwrap();
+
+    let verdict = submission.send(&site.downloader()).await.unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+    assert_eq!(
+        answers::read(answers_file(&submission)).unwrap(),
+        [Some("37".to_string()), Some("168".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn never_sends_answers_earlier_guesses_rule_out() {
+    let site = Site::new(200, CORRECT);
+    let dir = TempDir::new("submit-ruled-out");
+    let submission = submission(&dir, 1, "600");
+    guesses::record(&submission.guesses, "500", &Verdict::TooHigh).unwrap();
+
+    let result = submission.send(&site.downloader()).await;
+    assert!(matches!(
+        result,
+        Err(SubmitError::KnownWrong(_, Conflict::NotBelow(500)))
+    ));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Not submitting 600: 500 was already too high"
+    );
+    assert!(site.requests().is_empty());
+    assert_eq!(guesses::load(&submission.guesses).unwrap().len(), 1);
+}